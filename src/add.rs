@@ -64,7 +64,7 @@ impl Add<isize> for Polynomial {
     }
 }
 
-impl<'a> Add<isize> for &'a Polynomial {
+impl Add<isize> for &Polynomial {
     type Output = Polynomial;
     fn add(self, rhs: isize) -> Self::Output {
         if self.is_zero() {
@@ -105,7 +105,7 @@ impl AddAssign for Polynomial {
     }
 }
 
-impl<'a> AddAssign<&'a Polynomial> for Polynomial {
+impl AddAssign<&Polynomial> for Polynomial {
     fn add_assign(&mut self, rhs: &Self) {
         if self.is_zero() {
             self.coeffs = rhs.coeffs.clone();
@@ -0,0 +1,134 @@
+use polynomint::expr::{Expr, ExprError, ExprErrorKind};
+use polynomint::factor::Factorization;
+use polynomint::Polynomial;
+
+/// The commands that can make up a whole line, but not appear inside an expression.
+const COMMANDS: [&str; 2] = ["factor", "roots"];
+
+/// The result of evaluating a line: a polynomial, unless the line was a `factor(...)` or
+/// `roots(...)` command.
+pub enum Value {
+    Poly(Polynomial),
+    Factored(Factorization),
+    Roots(Vec<isize>),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Poly(poly) => write!(f, "{}", poly),
+            Value::Factored(factorization) => write!(f, "{}", factorization),
+            Value::Roots(roots) => {
+                let roots: Vec<String> = roots.iter().map(|r| r.to_string()).collect();
+                write!(f, "[{}]", roots.join(", "))
+            }
+        }
+    }
+}
+
+/// Evaluates one line of input: an expression (see `polynomint::expr`) or one of the
+/// commands `factor(p)` and `roots(p)`, optionally followed by `mod n`.
+pub fn eval_line(line: &str) -> Result<Value, String> {
+    let (body, modulus) = split_modulus(line)?;
+    let (command, args) = match split_command(body) {
+        Some(command) => command,
+        None => return eval(body, 0, modulus).map(Value::Poly),
+    };
+    let (arg, at) = match args.as_slice() {
+        &[arg] => arg,
+        _ => {
+            return Err(format!(
+                "{}() takes 1 argument(s), found {}",
+                command,
+                args.len()
+            ))
+        }
+    };
+    let poly = eval(arg, at, modulus)?;
+    match (command, modulus) {
+        ("factor", None) => Ok(Value::Factored(poly.factor())),
+        ("factor", Some(_)) => Err(String::from("factor() doesn't support 'mod'")),
+        (_, None) => Ok(Value::Roots(poly.integer_roots())),
+        (_, Some(p)) => poly
+            .try_roots_mod(p)
+            .map(Value::Roots)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// Parses and evaluates the expression `s`, which starts `at` bytes into the line, so
+/// that errors point at the right place.
+fn eval(s: &str, at: usize, modulus: Option<isize>) -> Result<Polynomial, String> {
+    let expr = Expr::parse(s).map_err(|e| describe(e, at))?;
+    match modulus {
+        Some(p) => expr.eval_mod(p),
+        None => expr.eval(),
+    }
+    .map_err(|e| describe(e, at))
+}
+
+fn describe(e: ExprError, at: usize) -> String {
+    match e.kind() {
+        // their results aren't polynomials, so they can't be part of an expression
+        ExprErrorKind::UnknownFunction(name) if COMMANDS.contains(&name.as_str()) => format!(
+            "{}() can only be used on its own at position {}",
+            name,
+            e.position() + at
+        ),
+        kind => format!("{} at position {}", kind, e.position() + at),
+    }
+}
+
+/// Splits a trailing `mod n` off the line.
+fn split_modulus(line: &str) -> Result<(&str, Option<isize>), String> {
+    let found = line.rmatch_indices("mod").find(|&(i, _)| {
+        let before = line[..i].chars().next_back();
+        let after = line[i + 3..].chars().next();
+        before.is_none_or(|c| c.is_whitespace() || c == ')')
+            && after.is_none_or(char::is_whitespace)
+    });
+    match found {
+        Some((i, _)) => match line[i + 3..].trim().parse() {
+            Ok(p) if p >= 2 => Ok((&line[..i], Some(p))),
+            _ => Err(format!("invalid modulus at position {}", i + 3)),
+        },
+        None => Ok((line, None)),
+    }
+}
+
+/// Recognizes a line that's entirely a call to one of the commands, giving the command and
+/// its arguments along with where each one starts.
+fn split_command(body: &str) -> Option<(&'static str, Vec<(&str, usize)>)> {
+    let start = body.len() - body.trim_start().len();
+    let trimmed = body.trim();
+    let command = COMMANDS
+        .iter()
+        .find(|&&command| trimmed.starts_with(command))?;
+    let open = start + command.len() + trimmed[command.len()..].find('(')?;
+    if !body[start + command.len()..open].trim().is_empty() {
+        return None;
+    }
+    let mut depth = 0;
+    let mut args = Vec::new();
+    let mut arg_start = open + 1;
+    for (i, c) in body.char_indices().skip_while(|&(i, _)| i <= open) {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                // the closing parenthesis has to end the line
+                if !body[i + 1..].trim().is_empty() {
+                    return None;
+                }
+                args.push((&body[arg_start..i], arg_start));
+                return Some((command, args));
+            }
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push((&body[arg_start..i], arg_start));
+                arg_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
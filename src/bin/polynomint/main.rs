@@ -0,0 +1,104 @@
+//! A calculator for integer polynomials.
+//!
+//! Each line of input is an expression in `x`, as understood by `polynomint::expr`, or
+//! one of the commands `factor(p)` and `roots(p)`, optionally followed by
+//! `mod n` to do all the arithmetic modulo `n`.
+//!
+//! With expressions as arguments, evaluates each of them; otherwise reads lines from
+//! standard input, with a prompt if it's a terminal.
+
+mod calc;
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage: polynomint [EXPRESSION...]
+
+Evaluates each EXPRESSION, or reads expressions from standard input one per line.
+
+examples:
+    (x + 1)^5 * (x - 2) mod 7
+    gcd(x^2 - 1, x^2 + 2x + 1)
+    factor(2x^3 - 3x^2 - 2x + 3)
+    roots(x^3 - 7x + 6)
+    derivative(compose(x^2, x + 1))
+    eval(gcd(x^2 - 1, x^2 + 2x + 1), 3) + 1";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    if !args.is_empty() {
+        return batch(args.iter().map(|arg| Ok(arg.clone())), false);
+    }
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        repl(stdin.lock());
+        ExitCode::SUCCESS
+    } else {
+        batch(stdin.lock().lines(), true)
+    }
+}
+
+/// Evaluates every line, printing results to stdout and errors to stderr; blank lines and
+/// lines starting with `#` are skipped. Fails if any line did.
+fn batch(lines: impl Iterator<Item = io::Result<String>>, numbered: bool) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for (n, line) in lines.enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match calc::eval_line(line) {
+            Ok(value) => println!("{}", value),
+            Err(e) if numbered => {
+                eprintln!("line {}: error: {}", n + 1, e);
+                status = ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("{}: error: {}", line, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn repl(mut input: impl BufRead) {
+    let mut line = String::new();
+    loop {
+        print!("> ");
+        let _ = io::stdout().flush();
+        line.clear();
+        match input.read_line(&mut line) {
+            Ok(0) => {
+                println!();
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return;
+            }
+        }
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => return,
+            "help" => println!("{}", USAGE),
+            line => match calc::eval_line(line) {
+                Ok(value) => println!("{}", value),
+                Err(e) => println!("error: {}", e),
+            },
+        }
+    }
+}
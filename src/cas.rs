@@ -0,0 +1,371 @@
+use crate::format::PolyFormatter;
+use crate::parse::{ParseError, ParseErrorKind};
+use crate::Polynomial;
+
+use Piece::*;
+
+/// The computer algebra systems whose syntax `to_cas_string()` and `from_cas_str()` know.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasDialect {
+    /// PARI/GP; polynomials mod `p` are written `Mod(1, p)*(...)`.
+    Pari,
+    /// SageMath; polynomials mod `p` are written `GF(p)['x'](...)`.
+    Sage,
+    /// Mathematica; polynomials mod `p` are written `PolynomialMod[..., p]`.
+    Mathematica,
+    /// SymPy; polynomials mod `p` are written `Poly(..., x, modulus=p)`.
+    SymPy,
+}
+
+/// One piece of the shape of a modular wrapper, for matching against input.
+#[derive(Clone, Copy)]
+enum Piece {
+    /// This exact token, possibly preceded by whitespace.
+    Lit(&'static str),
+    /// The variable name `x` in single or double quotes.
+    QuotedVar,
+    /// The modulus.
+    Modulus,
+    /// The polynomial itself.
+    Body,
+}
+
+const PARI_WRAPPERS: &[&[Piece]] = &[
+    &[
+        Lit("Mod"),
+        Lit("("),
+        Lit("1"),
+        Lit(","),
+        Modulus,
+        Lit(")"),
+        Lit("*"),
+        Lit("("),
+        Body,
+        Lit(")"),
+    ],
+    &[
+        Lit("("),
+        Body,
+        Lit(")"),
+        Lit("*"),
+        Lit("Mod"),
+        Lit("("),
+        Lit("1"),
+        Lit(","),
+        Modulus,
+        Lit(")"),
+    ],
+    &[Lit("Mod"), Lit("("), Body, Lit(","), Modulus, Lit(")")],
+];
+
+const SAGE_WRAPPERS: &[&[Piece]] = &[
+    &[
+        Lit("GF"),
+        Lit("("),
+        Modulus,
+        Lit(")"),
+        Lit("["),
+        QuotedVar,
+        Lit("]"),
+        Lit("("),
+        Body,
+        Lit(")"),
+    ],
+    &[
+        Lit("PolynomialRing"),
+        Lit("("),
+        Lit("GF"),
+        Lit("("),
+        Modulus,
+        Lit(")"),
+        Lit(","),
+        QuotedVar,
+        Lit(")"),
+        Lit("("),
+        Body,
+        Lit(")"),
+    ],
+];
+
+const MATHEMATICA_WRAPPERS: &[&[Piece]] = &[&[
+    Lit("PolynomialMod"),
+    Lit("["),
+    Body,
+    Lit(","),
+    Modulus,
+    Lit("]"),
+]];
+
+const SYMPY_WRAPPERS: &[&[Piece]] = &[
+    &[
+        Lit("Poly"),
+        Lit("("),
+        Body,
+        Lit(","),
+        Lit("x"),
+        Lit(","),
+        Lit("modulus"),
+        Lit("="),
+        Modulus,
+        Lit(")"),
+    ],
+    &[
+        Lit("Poly"),
+        Lit("("),
+        Body,
+        Lit(","),
+        Lit("x"),
+        Lit(","),
+        Lit("domain"),
+        Lit("="),
+        Lit("'ZZ'"),
+        Lit(")"),
+    ],
+    &[Lit("Poly"), Lit("("), Body, Lit(","), Lit("x"), Lit(")")],
+];
+
+impl CasDialect {
+    fn wrappers(self) -> &'static [&'static [Piece]] {
+        match self {
+            Self::Pari => PARI_WRAPPERS,
+            Self::Sage => SAGE_WRAPPERS,
+            Self::Mathematica => MATHEMATICA_WRAPPERS,
+            Self::SymPy => SYMPY_WRAPPERS,
+        }
+    }
+
+    fn formatter(self) -> PolyFormatter {
+        let formatter = PolyFormatter::new().explicit_mul(true);
+        match self {
+            Self::SymPy => formatter.power_op("**"),
+            _ => formatter,
+        }
+    }
+}
+
+impl Polynomial {
+    /// Writes the polynomial out as an expression in the variable `x` that the given
+    /// computer algebra system can read.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, cas::CasDialect};
+    ///
+    /// let poly = poly![-5, 2, 0, -1];
+    /// assert_eq!(poly.to_cas_string(CasDialect::Pari), "-x^3 + 2*x - 5");
+    /// assert_eq!(poly.to_cas_string(CasDialect::SymPy), "-x**3 + 2*x - 5");
+    /// ```
+    pub fn to_cas_string(&self, dialect: CasDialect) -> String {
+        dialect.formatter().format(self).to_string()
+    }
+
+    /// Writes the polynomial out, taken modulo `p`, wrapped up the way the given computer
+    /// algebra system writes polynomials with coefficients in the integers mod `p`. The
+    /// coefficients are reduced into the range `0..p` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, cas::CasDialect};
+    ///
+    /// let poly = poly![-5, 2, 0, -1];
+    /// assert_eq!(poly.to_cas_string_mod(CasDialect::Pari, 7), "Mod(1, 7)*(6*x^3 + 2*x + 2)");
+    /// assert_eq!(poly.to_cas_string_mod(CasDialect::Sage, 7), "GF(7)['x'](6*x^3 + 2*x + 2)");
+    /// assert_eq!(
+    ///     poly.to_cas_string_mod(CasDialect::Mathematica, 7),
+    ///     "PolynomialMod[6*x^3 + 2*x + 2, 7]"
+    /// );
+    /// assert_eq!(
+    ///     poly.to_cas_string_mod(CasDialect::SymPy, 7),
+    ///     "Poly(6*x**3 + 2*x + 2, x, modulus=7)"
+    /// );
+    /// ```
+    pub fn to_cas_string_mod(&self, dialect: CasDialect, p: isize) -> String {
+        let body = self.rem_euclid(p).to_cas_string(dialect);
+        match dialect {
+            CasDialect::Pari => format!("Mod(1, {})*({})", p, body),
+            CasDialect::Sage => format!("GF({})['x']({})", p, body),
+            CasDialect::Mathematica => format!("PolynomialMod[{}, {}]", body, p),
+            CasDialect::SymPy => format!("Poly({}, x, modulus={})", body, p),
+        }
+    }
+
+    /// Reads a polynomial in the variable `x` written in the syntax of the given computer
+    /// algebra system, optionally wrapped up as a polynomial modulo some `p` in one of the
+    /// ways that system writes it. Returns the polynomial along with the modulus, if there
+    /// was one; polynomials with a modulus have their coefficients reduced into `0..p`.
+    ///
+    /// Besides the forms written by `to_cas_string_mod()`, this reads PARI/GP's
+    /// `(...)*Mod(1, p)` and `Mod(..., p)`, along with the way it prints polynomials mod
+    /// `p`, with a `Mod(a, p)` for every coefficient; Sage's
+    /// `PolynomialRing(GF(p), 'x')(...)`; and SymPy's `Poly(..., x)` without a modulus,
+    /// including the way it prints them, `Poly(..., x, domain='ZZ')`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, cas::CasDialect};
+    ///
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("-5 + 2 x - x^3", CasDialect::Mathematica),
+    ///     Ok((poly![-5, 2, 0, -1], None))
+    /// );
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("(x^2 - 1)*Mod(1,5)", CasDialect::Pari),
+    ///     Ok((poly![4, 0, 1], Some(5)))
+    /// );
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("Poly(x**2 - 1, x, modulus=5)", CasDialect::SymPy),
+    ///     Ok((poly![4, 0, 1], Some(5)))
+    /// );
+    ///
+    /// // as printed by `(x^3 + 2*x + 2)*Mod(1, 7)` in PARI/GP
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("Mod(1, 7)*x^3 + Mod(2, 7)*x + Mod(2, 7)", CasDialect::Pari),
+    ///     Ok((poly![2, 2, 0, 1], Some(7)))
+    /// );
+    /// // as printed by `Poly(x**2 - 1, x)` and `Poly(6*x**3 + 2*x + 2, x, modulus=7)` in SymPy
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("Poly(x**2 - 1, x, domain='ZZ')", CasDialect::SymPy),
+    ///     Ok((poly![-1, 0, 1], None))
+    /// );
+    /// assert_eq!(
+    ///     Polynomial::from_cas_str("Poly(-x**3 + 2*x + 2, x, modulus=7)", CasDialect::SymPy),
+    ///     Ok((poly![2, 2, 0, 6], Some(7)))
+    /// );
+    ///
+    /// let poly = poly![3, 0, -2, 1];
+    /// for &dialect in &[CasDialect::Pari, CasDialect::Sage, CasDialect::Mathematica, CasDialect::SymPy] {
+    ///     assert_eq!(
+    ///         Polynomial::from_cas_str(&poly.to_cas_string(dialect), dialect),
+    ///         Ok((poly.clone(), None))
+    ///     );
+    ///     assert_eq!(
+    ///         Polynomial::from_cas_str(&poly.to_cas_string_mod(dialect, 11), dialect),
+    ///         Ok((poly.rem_euclid(11), Some(11)))
+    ///     );
+    /// }
+    /// ```
+    pub fn from_cas_str(s: &str, dialect: CasDialect) -> Result<(Self, Option<isize>), ParseError> {
+        if dialect == CasDialect::Pari {
+            if let Some((blanked, (start, end))) = blank_pari_mods(s) {
+                if let Ok(poly) = Self::parse_with_var(&blanked, "x") {
+                    return match s[start..end].parse::<isize>() {
+                        Ok(p) if p >= 2 => Ok((poly.rem_euclid(p), Some(p))),
+                        _ => Err(ParseError::new(ParseErrorKind::InvalidModulus, start)),
+                    };
+                }
+            }
+        }
+        for wrapper in dialect.wrappers() {
+            if let Some((body, modulus)) = match_wrapper(s, 0, wrapper) {
+                let poly =
+                    Self::parse_with_var(&s[body.0..body.1], "x").map_err(|e| e.offset(body.0))?;
+                return match modulus {
+                    Some((start, end)) => match s[start..end].parse::<isize>() {
+                        Ok(p) if p >= 2 => Ok((poly.rem_euclid(p), Some(p))),
+                        _ => Err(ParseError::new(ParseErrorKind::InvalidModulus, start)),
+                    },
+                    None => Ok((poly, None)),
+                };
+            }
+        }
+        Self::parse_with_var(s, "x").map(|poly| (poly, None))
+    }
+}
+
+type Span = (usize, usize);
+
+/// Turns PARI/GP's way of printing a polynomial mod `p`, like `Mod(1, 7)*x^3 + Mod(2, 7)`,
+/// into an ordinary polynomial by blanking out everything in each `Mod(a, p)` but the `a`,
+/// which keeps every position the same for error messages. Gives `None` unless there's at
+/// least one of them and they all have the same modulus; otherwise gives the span of the
+/// first modulus.
+fn blank_pari_mods(s: &str) -> Option<(String, Span)> {
+    let mut blanked = String::from(s);
+    let mut modulus: Option<Span> = None;
+    let digits = |pos: usize| {
+        let pos = pos + (s[pos..].len() - s[pos..].trim_start().len());
+        let len = s[pos..].len()
+            - s[pos..]
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if len == 0 {
+            None
+        } else {
+            Some((pos, pos + len))
+        }
+    };
+    let lit = |pos: usize, lit: &str| {
+        let pos = pos + (s[pos..].len() - s[pos..].trim_start().len());
+        if s[pos..].starts_with(lit) {
+            Some(pos + lit.len())
+        } else {
+            None
+        }
+    };
+    for (start, _) in s.match_indices("Mod(") {
+        let (_, coeff_end) = digits(start + 4)?;
+        let (p_start, p_end) = digits(lit(coeff_end, ",")?)?;
+        let end = lit(p_end, ")")?;
+        match modulus {
+            Some((first, first_end)) if s[first..first_end] != s[p_start..p_end] => return None,
+            Some(_) => {}
+            None => modulus = Some((p_start, p_end)),
+        }
+        // "Mod(" and ", p)" are all ASCII, so this keeps the length
+        blanked.replace_range(start..start + 4, "    ");
+        blanked.replace_range(coeff_end..end, &" ".repeat(end - coeff_end));
+    }
+    modulus.map(|modulus| (blanked, modulus))
+}
+
+/// Tries to match the whole of `s[pos..]` against `pieces`, giving the spans of the body
+/// and (if the pieces include one) the modulus.
+fn match_wrapper(s: &str, pos: usize, pieces: &[Piece]) -> Option<(Span, Option<Span>)> {
+    let skip_space = |pos: usize| pos + (s[pos..].len() - s[pos..].trim_start().len());
+    match pieces.split_first() {
+        None => {
+            if skip_space(pos) == s.len() {
+                Some(((0, 0), None))
+            } else {
+                None
+            }
+        }
+        Some((Lit(lit), rest)) => {
+            let pos = skip_space(pos);
+            if s[pos..].starts_with(lit) {
+                match_wrapper(s, pos + lit.len(), rest)
+            } else {
+                None
+            }
+        }
+        Some((QuotedVar, rest)) => {
+            let pos = skip_space(pos);
+            if s[pos..].starts_with("'x'") || s[pos..].starts_with("\"x\"") {
+                match_wrapper(s, pos + 3, rest)
+            } else {
+                None
+            }
+        }
+        Some((Modulus, rest)) => {
+            let start = skip_space(pos);
+            let end = start + s[start..].len()
+                - s[start..]
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .len();
+            let (body, _) = match_wrapper(s, end, rest)?;
+            Some((body, Some((start, end))))
+        }
+        Some((Body, rest)) => {
+            // the body can contain anything, so try every place it could end, from the
+            // furthest back, until the rest of the pieces match after it
+            let start = skip_space(pos);
+            (start..=s.len())
+                .rev()
+                .filter(|&end| s.is_char_boundary(end))
+                .find_map(|end| {
+                    let (_, modulus) = match_wrapper(s, end, rest)?;
+                    Some(((start, end), modulus))
+                })
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+
+use crate::Polynomial;
+
+/// The ways combining modular images with `Polynomial::crt()` can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// One of the moduli is less than 2.
+    InvalidModulus(isize),
+    /// This modulus shares a factor with one of the moduli before it.
+    NotCoprime(isize),
+    /// The product of the moduli, or one of the reconstructed coefficients, doesn't fit
+    /// in the integer types used.
+    Overflow,
+}
+
+impl std::fmt::Display for CrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidModulus(m) => write!(f, "modulus {} is less than 2", m),
+            Self::NotCoprime(m) => {
+                write!(f, "modulus {} is not coprime to the previous moduli", m)
+            }
+            Self::Overflow => write!(f, "reconstructed coefficients overflow"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+impl Polynomial {
+    /// Given images `(p_i, m_i)` of some polynomial modulo pairwise coprime moduli `m_i`,
+    /// reconstructs the unique polynomial which is congruent to each `p_i` modulo `m_i`
+    /// and whose coefficients lie in the symmetric range `(-M/2, M/2]`, where `M` is the
+    /// product of the moduli. (So as long as the true coefficients are less than `M/2` in
+    /// absolute value, this gives back the true polynomial.) An empty list of images
+    /// gives the zero polynomial.
+    ///
+    /// The images can have coefficients in any range; they're reduced modulo `m_i` first.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, crt::CrtError};
+    ///
+    /// let poly = poly![-40, 3, 0, 17];
+    /// let images: Vec<(Polynomial, isize)> = [7, 11, 13]
+    ///     .iter()
+    ///     .map(|&m| (poly.rem_euclid(m), m))
+    ///     .collect();
+    /// assert_eq!(Polynomial::crt(&images), Ok(poly));
+    ///
+    /// // 5 = -1 mod 3 and 5 = 0 mod 5, and 5 is in the symmetric range (-7.5, 7.5]
+    /// assert_eq!(Polynomial::crt(&[(poly![-1], 3), (poly![0], 5)]), Ok(poly![5]));
+    ///
+    /// assert_eq!(Polynomial::crt(&[(poly![1], 6), (poly![1], 9)]), Err(CrtError::NotCoprime(9)));
+    /// assert_eq!(Polynomial::crt(&[(poly![1], 1)]), Err(CrtError::InvalidModulus(1)));
+    /// ```
+    pub fn crt(images: &[(Polynomial, isize)]) -> Result<Self, CrtError> {
+        let len = images
+            .iter()
+            .map(|(poly, _)| poly.coeffs.len())
+            .max()
+            .unwrap_or(0);
+        // the running combination: coefficients in 0..modulus, where modulus is the
+        // product of all the moduli seen so far
+        let mut coeffs = vec![0i128; len];
+        let mut modulus: i128 = 1;
+        for (poly, m) in images {
+            let m = *m;
+            if m < 2 {
+                return Err(CrtError::InvalidModulus(m));
+            }
+            let prev = modulus.rem_euclid(m as i128) as isize;
+            if Self::int_gcd(prev, m) != 1 {
+                return Err(CrtError::NotCoprime(m));
+            }
+            let inv = Self::inv_mod_p(prev, m) as i128;
+            let next = modulus.checked_mul(m as i128).ok_or(CrtError::Overflow)?;
+            // Garner's step: we want c + modulus * t = a (mod m), so
+            // t = (a - c) * modulus^-1 (mod m), and c + modulus * t is then in 0..next
+            for (i, c) in coeffs.iter_mut().enumerate() {
+                let a = poly.coeffs.get(i).copied().unwrap_or(0) as i128;
+                let t = ((a - *c).rem_euclid(m as i128) * inv).rem_euclid(m as i128);
+                *c += modulus * t;
+            }
+            modulus = next;
+        }
+        coeffs
+            .into_iter()
+            .map(|c| {
+                let c = if c > modulus / 2 { c - modulus } else { c };
+                isize::try_from(c).map_err(|_| CrtError::Overflow)
+            })
+            .collect::<Result<Vec<isize>, CrtError>>()
+            .map(Self::new)
+    }
+}
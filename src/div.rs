@@ -0,0 +1,65 @@
+use crate::Polynomial;
+
+impl Polynomial {
+    /// Divides `self` by `divisor` with remainder, returning `Some((q, r))` where
+    /// `self = q * divisor + r` and `r.degree() < divisor.degree()`. Since we're working
+    /// over the integers, this can only be done if every leading coefficient that comes
+    /// up during long division is divisible by the leading coefficient of `divisor`
+    /// (which is always the case when that leading coefficient is 1 or -1); if it isn't,
+    /// or if `divisor` is zero, returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, 2, 0, 1]; // x^3 + 2x + 1
+    /// let divisor = poly![1, 1]; // x + 1
+    /// assert_eq!(poly.div_rem(&divisor), Some((poly![3, -1, 1], poly![-2])));
+    ///
+    /// let even = poly![2, 4, 6]; // 6x^2 + 4x + 2
+    /// assert_eq!(even.div_rem(&poly![0, 2]), Some((poly![2, 3], poly![2])));
+    /// assert_eq!(even.div_rem(&poly![0, 4]), None);
+    /// assert_eq!(even.div_rem(&Polynomial::zero()), None);
+    /// ```
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if self.degree() < divisor.degree() {
+            return Some((Self::zero(), self.clone()));
+        }
+        let ddeg = divisor.degree() as usize;
+        let lead = divisor.coeffs[ddeg];
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![0; rem.len() - ddeg];
+        // standard long division: knock out the top coefficient of the running
+        // remainder one power at a time, from the top down
+        for n in (ddeg..rem.len()).rev() {
+            if rem[n] % lead != 0 {
+                return None;
+            }
+            let q = rem[n] / lead;
+            quot[n - ddeg] = q;
+            for (i, &d) in divisor.coeffs.iter().enumerate() {
+                rem[n - ddeg + i] -= q * d;
+            }
+        }
+        rem.truncate(ddeg);
+        Some((Self::new(quot), Self::new(rem)))
+    }
+
+    /// Gives the remainder of `self` when divided by `divisor`, if the division can be
+    /// carried out over the integers; see `div_rem()` for when this is the case.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![-1, 0, 0, 0, 1]; // x^4 - 1
+    /// assert_eq!(poly.rem_poly(&poly![1, 0, 1]), Some(Polynomial::zero()));
+    /// assert_eq!(poly.rem_poly(&poly![-2, 1]), Some(poly![15]));
+    /// ```
+    pub fn rem_poly(&self, divisor: &Self) -> Option<Self> {
+        self.div_rem(divisor).map(|(_, rem)| rem)
+    }
+}
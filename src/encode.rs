@@ -0,0 +1,284 @@
+use std::convert::TryFrom;
+
+use crate::Polynomial;
+
+/// The version of the binary format written by `to_bytes()`, which is its first byte.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// The highest power `from_bytes()` accepts in a sparse polynomial, since decoding
+/// allocates a coefficient for every power up to it; a few bytes of sparse input could
+/// otherwise ask for any amount of memory.
+pub const MAX_SPARSE_DEGREE: usize = 1 << 24;
+
+const DENSE: u8 = 0;
+const SPARSE: u8 = 1;
+
+/// The ways decoding a polynomial with `Polynomial::from_bytes()` can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input was written by a version of the format this crate doesn't know.
+    UnsupportedVersion(u8),
+    /// The byte after the version says neither dense nor sparse.
+    UnknownEncoding(u8),
+    /// The input ended in the middle of a polynomial.
+    Truncated,
+    /// There are bytes left over after the end of the polynomial.
+    TrailingBytes,
+    /// A varint doesn't fit in 64 bits, or a coefficient doesn't fit in an `isize`.
+    Overflow,
+    /// The leading coefficient of a dense polynomial, or any coefficient of a sparse one,
+    /// is zero; `to_bytes()` never writes these, so the input is corrupt.
+    ZeroCoefficient,
+    /// Two terms of a sparse polynomial have the same power.
+    RepeatedPower,
+    /// A sparse polynomial has a power above `MAX_SPARSE_DEGREE`.
+    DegreeTooLarge,
+    /// The input is a polynomial, but not as `to_bytes()` would write it: a varint has
+    /// needless trailing zero groups, or the encoding isn't the one `to_bytes()` picks.
+    NonCanonical,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedVersion(v) => write!(f, "unsupported format version {}", v),
+            Self::UnknownEncoding(e) => write!(f, "unknown encoding {}", e),
+            Self::Truncated => write!(f, "input is truncated"),
+            Self::TrailingBytes => write!(f, "unexpected bytes after end of polynomial"),
+            Self::Overflow => write!(f, "encoded value out of range"),
+            Self::ZeroCoefficient => write!(f, "unexpected zero coefficient"),
+            Self::RepeatedPower => write!(f, "repeated power in sparse polynomial"),
+            Self::DegreeTooLarge => write!(f, "degree of sparse polynomial is too large"),
+            Self::NonCanonical => write!(f, "input is not in canonical form"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl Polynomial {
+    /// Encodes the polynomial into a compact binary format. The first byte is the format
+    /// version (`FORMAT_VERSION`); the second says whether the rest is dense or sparse.
+    /// A dense polynomial is its number of coefficients followed by the coefficients
+    /// themselves, in ascending order; a sparse one is its number of nonzero terms, followed
+    /// by, for each one, the gap since the previous term's power and then its coefficient.
+    /// All numbers are LEB128 varints, with coefficients zigzag-encoded first so that small
+    /// negative numbers stay small. Whichever of dense or sparse is shorter gets used,
+    /// except that sparse is never used past `MAX_SPARSE_DEGREE`, so that `from_bytes()`
+    /// can read back anything this writes. Every polynomial has exactly one encoding.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, -2, 1];
+    /// assert_eq!(poly.to_bytes(), vec![1, 0, 3, 2, 3, 2]);
+    /// assert_eq!(Polynomial::from_bytes(&poly.to_bytes()), Ok(poly));
+    ///
+    /// // x^1000 + 1 takes 8 bytes rather than over a thousand
+    /// let mut coeffs = vec![0; 1001];
+    /// coeffs[0] = 1;
+    /// coeffs[1000] = 1;
+    /// let sparse = Polynomial::new(coeffs);
+    /// assert_eq!(sparse.to_bytes().len(), 8);
+    /// assert_eq!(Polynomial::from_bytes(&sparse.to_bytes()), Ok(sparse));
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if self.is_sparse_encoded() {
+            let mut sparse = vec![FORMAT_VERSION, SPARSE];
+            write_varint(&mut sparse, self.sparse_terms().count() as u64);
+            let mut prev = 0;
+            for (n, coeff) in self.sparse_terms() {
+                write_varint(&mut sparse, (n - prev) as u64);
+                write_varint(&mut sparse, zigzag(coeff));
+                prev = n;
+            }
+            sparse
+        } else {
+            let mut dense = vec![FORMAT_VERSION, DENSE];
+            write_varint(&mut dense, self.coeffs.len() as u64);
+            for &coeff in self.coeffs.iter() {
+                write_varint(&mut dense, zigzag(coeff));
+            }
+            dense
+        }
+    }
+
+    fn sparse_terms(&self) -> impl Iterator<Item = (usize, isize)> + '_ {
+        let terms = self.coeffs.iter().copied().enumerate();
+        terms.filter(|&(_, c)| c != 0)
+    }
+
+    /// Whether `to_bytes()` writes the polynomial sparse: that is, if that's shorter than
+    /// dense and within `MAX_SPARSE_DEGREE`. The lengths are worked out without writing
+    /// either encoding, since `from_bytes()` asks this of everything it decodes.
+    fn is_sparse_encoded(&self) -> bool {
+        if self.coeffs.len() > MAX_SPARSE_DEGREE + 1 {
+            return false;
+        }
+        let coeffs = self.coeffs.iter().map(|&c| varint_len(zigzag(c)));
+        let dense = varint_len(self.coeffs.len() as u64) + coeffs.sum::<usize>();
+        let mut sparse = varint_len(self.sparse_terms().count() as u64);
+        let mut prev = 0;
+        for (n, coeff) in self.sparse_terms() {
+            sparse += varint_len((n - prev) as u64) + varint_len(zigzag(coeff));
+            prev = n;
+        }
+        sparse < dense
+    }
+
+    /// Decodes a polynomial written by `to_bytes()`, checking that the input is exactly
+    /// one well-formed polynomial, written exactly as `to_bytes()` would write it. Sparse
+    /// input with a power above `MAX_SPARSE_DEGREE` is rejected rather than allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, encode::DecodeError};
+    ///
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 2, 1, 4]), Ok(poly![-1, 2]));
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 0]), Ok(Polynomial::zero()));
+    ///
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 2, 1]), Err(DecodeError::Truncated));
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 2, 1, 0]), Err(DecodeError::ZeroCoefficient));
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 0, 7]), Err(DecodeError::TrailingBytes));
+    /// assert_eq!(Polynomial::from_bytes(&[2, 0, 0]), Err(DecodeError::UnsupportedVersion(2)));
+    ///
+    /// // a padded varint, and a sparse encoding that's no shorter than dense
+    /// assert_eq!(Polynomial::from_bytes(&[1, 0, 0x82, 0, 1, 4]), Err(DecodeError::NonCanonical));
+    /// assert_eq!(Polynomial::from_bytes(&[1, 1, 0]), Err(DecodeError::NonCanonical));
+    ///
+    /// // powers that add up past usize::MAX, or past MAX_SPARSE_DEGREE
+    /// let huge = [1, 1, 1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 2];
+    /// assert_eq!(Polynomial::from_bytes(&huge), Err(DecodeError::DegreeTooLarge));
+    /// let big = [1, 1, 1, 0x80, 0x80, 0x80, 0x80, 0x80, 0x20, 2];
+    /// assert_eq!(Polynomial::from_bytes(&big), Err(DecodeError::DegreeTooLarge));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let version = reader.byte()?;
+        if version != FORMAT_VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let encoding = reader.byte()?;
+        let coeffs = match encoding {
+            DENSE => {
+                let len = reader.varint()?;
+                // every coefficient takes at least a byte, so if there aren't that many
+                // left, the input is cut short; checking now also keeps a corrupt length
+                // from making us allocate a huge vector
+                if len > (bytes.len() - reader.pos) as u64 {
+                    return Err(DecodeError::Truncated);
+                }
+                let mut coeffs = Vec::with_capacity(len as usize);
+                for _ in 0..len {
+                    coeffs.push(reader.coeff()?);
+                }
+                if coeffs.last() == Some(&0) {
+                    return Err(DecodeError::ZeroCoefficient);
+                }
+                coeffs
+            }
+            SPARSE => {
+                let count = reader.varint()?;
+                // likewise, every term takes at least two bytes
+                if count > (bytes.len() - reader.pos) as u64 / 2 {
+                    return Err(DecodeError::Truncated);
+                }
+                let mut terms = Vec::with_capacity(count as usize);
+                let mut power: usize = 0;
+                for i in 0..count {
+                    let gap = reader.varint()?;
+                    // only the first term can have a gap of zero, i.e. be the constant
+                    if gap == 0 && i > 0 {
+                        return Err(DecodeError::RepeatedPower);
+                    }
+                    power = usize::try_from(gap)
+                        .ok()
+                        .and_then(|gap| power.checked_add(gap))
+                        .filter(|&power| power <= MAX_SPARSE_DEGREE)
+                        .ok_or(DecodeError::DegreeTooLarge)?;
+                    let coeff = reader.coeff()?;
+                    if coeff == 0 {
+                        return Err(DecodeError::ZeroCoefficient);
+                    }
+                    terms.push((power, coeff));
+                }
+                let len = match terms.last() {
+                    Some(&(n, _)) => n.checked_add(1).ok_or(DecodeError::DegreeTooLarge)?,
+                    None => 0,
+                };
+                let mut coeffs = vec![0; len];
+                for (n, coeff) in terms {
+                    coeffs[n] = coeff;
+                }
+                coeffs
+            }
+            other => return Err(DecodeError::UnknownEncoding(other)),
+        };
+        if reader.pos != bytes.len() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        let poly = Self { coeffs };
+        if poly.is_sparse_encoded() != (encoding == SPARSE) {
+            return Err(DecodeError::NonCanonical);
+        }
+        Ok(poly)
+    }
+}
+
+fn zigzag(n: isize) -> u64 {
+    ((n << 1) ^ (n >> (isize::BITS - 1))) as usize as u64
+}
+
+fn varint_len(n: u64) -> usize {
+    // seven bits to a byte, and at least one byte even for zero
+    (64 - n.leading_zeros() as usize).max(1).div_ceil(7)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self.bytes.get(self.pos).ok_or(DecodeError::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut n: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7f) as u64;
+            if shift == 63 && bits > 1 {
+                return Err(DecodeError::Overflow);
+            }
+            n |= bits << shift;
+            if byte & 0x80 == 0 {
+                // a last byte of zero adds nothing, so to_bytes() would have left it off
+                if byte == 0 && shift > 0 {
+                    return Err(DecodeError::NonCanonical);
+                }
+                return Ok(n);
+            }
+        }
+        Err(DecodeError::Overflow)
+    }
+
+    fn coeff(&mut self) -> Result<isize, DecodeError> {
+        let n = self.varint()?;
+        // undo the zigzag: even numbers are nonnegative, odd ones negative
+        let n = ((n >> 1) as i64) ^ -((n & 1) as i64);
+        isize::try_from(n).map_err(|_| DecodeError::Overflow)
+    }
+}
@@ -0,0 +1,34 @@
+/// The ways an operation on a polynomial can fail, as reported by the `try_*` methods
+/// (like `Polynomial::try_eval()`) that stand in for ones which would otherwise panic,
+/// overflow or give an uninformative `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolyError {
+    /// The index is past the last coefficient, of which there are `len`.
+    IndexOutOfRange { index: usize, len: usize },
+    /// The modulus is zero.
+    ZeroModulus,
+    /// The modulus has to be a prime, but isn't.
+    NonPrimeModulus(isize),
+    /// The value isn't a root of the polynomial.
+    NotARoot(isize),
+    /// The result, or something computed along the way, doesn't fit in an `isize`.
+    Overflow,
+}
+
+impl std::fmt::Display for PolyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfRange { index, len } => write!(
+                f,
+                "index {} out of range for polynomial with {} coefficients",
+                index, len
+            ),
+            Self::ZeroModulus => write!(f, "modulus is zero"),
+            Self::NonPrimeModulus(p) => write!(f, "modulus {} is not prime", p),
+            Self::NotARoot(a) => write!(f, "{} is not a root", a),
+            Self::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for PolyError {}
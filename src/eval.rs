@@ -0,0 +1,100 @@
+use std::ops::{Add, Mul};
+
+use crate::Polynomial;
+
+/// A type that integer polynomials can be evaluated at with `Polynomial::eval_at()`:
+/// anything with an addition and multiplication, plus a way to turn the polynomial's
+/// `isize` coefficients into elements of the same ring.
+///
+/// This is implemented for the primitive integer and floating-point types and for
+/// `Polynomial` itself; implement it for your own types (fractions, complex numbers,
+/// matrices, ...) to evaluate at those.
+///
+/// # Examples
+/// ```
+/// use std::ops::{Add, Mul};
+/// use polynomint::{Polynomial, poly, eval::RingElement};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Mat2([[isize; 2]; 2]);
+///
+/// impl Add for Mat2 {
+///     type Output = Self;
+///     fn add(self, rhs: Self) -> Self {
+///         let (a, b) = (self.0, rhs.0);
+///         Mat2([[a[0][0] + b[0][0], a[0][1] + b[0][1]], [a[1][0] + b[1][0], a[1][1] + b[1][1]]])
+///     }
+/// }
+///
+/// impl Mul for Mat2 {
+///     type Output = Self;
+///     fn mul(self, rhs: Self) -> Self {
+///         let (a, b) = (self.0, rhs.0);
+///         Mat2([
+///             [a[0][0] * b[0][0] + a[0][1] * b[1][0], a[0][0] * b[0][1] + a[0][1] * b[1][1]],
+///             [a[1][0] * b[0][0] + a[1][1] * b[1][0], a[1][0] * b[0][1] + a[1][1] * b[1][1]],
+///         ])
+///     }
+/// }
+///
+/// impl RingElement for Mat2 {
+///     fn from_isize(n: isize, _like: &Self) -> Self {
+///         Mat2([[n, 0], [0, n]])
+///     }
+/// }
+///
+/// // Cayley-Hamilton: a matrix is a root of its own characteristic polynomial
+/// let m = Mat2([[1, 2], [3, 4]]);
+/// let char_poly = poly![-2, -5, 1]; // x^2 - (trace)x + det
+/// assert_eq!(char_poly.eval_at(&m), Mat2([[0, 0], [0, 0]]));
+/// ```
+pub trait RingElement: Clone + Add<Output = Self> + Mul<Output = Self> {
+    /// Gives `n` times the multiplicative identity of the ring that `like` lives in.
+    /// The argument `like` is only there for types (like square matrices of arbitrary
+    /// size) whose identity depends on the particular value; most types can ignore it.
+    fn from_isize(n: isize, like: &Self) -> Self;
+}
+
+macro_rules! impl_ring_element {
+    ($($t:ty),*) => {
+        $(
+            impl RingElement for $t {
+                fn from_isize(n: isize, _like: &Self) -> Self {
+                    n as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_ring_element!(isize, i32, i64, i128, f32, f64);
+
+impl RingElement for Polynomial {
+    fn from_isize(n: isize, _like: &Self) -> Self {
+        Polynomial::constant(n)
+    }
+}
+
+impl Polynomial {
+    /// Plugs in a value `x` of any type implementing `RingElement` to the polynomial,
+    /// using Horner's scheme just like `eval()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![-2, 0, 1]; // x^2 - 2
+    ///
+    /// assert_eq!(poly.eval_at(&3isize), poly.eval(3));
+    /// assert_eq!(poly.eval_at(&1.5f64), 0.25);
+    /// assert!(poly.eval_at(&std::f64::consts::SQRT_2).abs() < 1e-12);
+    /// assert_eq!(poly.eval_at(&poly![1, 1]), poly![-1, 2, 1]); // (x + 1)^2 - 2
+    /// ```
+    pub fn eval_at<T: RingElement>(&self, x: &T) -> T {
+        let mut acc = T::from_isize(0, x);
+        for &i in self.coeffs.iter().rev() {
+            acc = acc * x.clone() + T::from_isize(i, x);
+        }
+        acc
+    }
+}
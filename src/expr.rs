@@ -0,0 +1,727 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::parse::MAX_PARSE_DEGREE;
+use crate::Polynomial;
+
+/// An error from parsing or evaluating an expression, along with the byte offset into the
+/// input at which it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    kind: ExprErrorKind,
+    position: usize,
+}
+
+/// The kinds of things that can go wrong when parsing or evaluating an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExprErrorKind {
+    /// The input ended where more was expected, like after a `+` or inside parentheses.
+    UnexpectedEnd,
+    /// This character doesn't make sense where it appears.
+    UnexpectedChar(char),
+    /// An integer literal doesn't fit in an `isize`.
+    NumberOverflow,
+    /// An exponent doesn't fit in a `u32`.
+    ExponentOverflow,
+    /// The variable name that was asked for is empty or isn't a word.
+    InvalidVariable,
+    /// A name that isn't the variable was used as if it were one.
+    UnknownVariable(String),
+    /// A function that doesn't exist was called.
+    UnknownFunction(String),
+    /// A function was called with the wrong number of arguments.
+    WrongArgumentCount { expected: usize, found: usize },
+    /// An argument that has to be a constant, like the modulus of `rem_euclid`, isn't one.
+    NonConstantArgument,
+    /// `rem_euclid` was asked to reduce modulo zero.
+    DivisionByZero,
+    /// A function that only makes sense over the integers, `gcd` or `rem_euclid`, was used
+    /// in `Expr::eval_mod()`.
+    NotModular,
+    /// The modulus given to `Expr::eval_mod()` is less than 2.
+    InvalidModulus,
+    /// A coefficient of the result, or of something computed along the way, doesn't fit
+    /// in an `isize`.
+    Overflow,
+    /// The result, or something computed along the way, would have a degree above
+    /// `MAX_PARSE_DEGREE`, the same limit that parsing a polynomial has.
+    DegreeTooLarge,
+}
+
+impl ExprError {
+    fn new(kind: ExprErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ExprErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset into the input at which it went wrong.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for ExprErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c),
+            Self::NumberOverflow => write!(f, "number out of range"),
+            Self::ExponentOverflow => write!(f, "exponent out of range"),
+            Self::InvalidVariable => write!(f, "invalid variable name"),
+            Self::UnknownVariable(name) => write!(f, "unknown variable '{}'", name),
+            Self::UnknownFunction(name) => write!(f, "unknown function '{}'", name),
+            Self::WrongArgumentCount { expected, found } => {
+                write!(f, "expected {} argument(s), found {}", expected, found)
+            }
+            Self::NonConstantArgument => write!(f, "argument must be a constant"),
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::NotModular => write!(f, "function not supported modulo n"),
+            Self::InvalidModulus => write!(f, "invalid modulus"),
+            Self::Overflow => write!(f, "arithmetic overflow"),
+            Self::DegreeTooLarge => write!(f, "degree too large"),
+        }
+    }
+}
+
+impl std::fmt::Display for ExprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+/// A parsed arithmetic expression whose value is a polynomial, like `(x + 1)^3 - 2x` or
+/// `derivative(compose(x^2, x - 1))`.
+///
+/// Expressions are made of integers, the variable, `+`, `-`, `*`, `^` (or `**`) with a
+/// nonnegative integer exponent, and parentheses; multiplication can also be written by
+/// juxtaposition, as in `2x` or `(x + 1)(x - 1)`. The functions `derivative(p)`,
+/// `compose(p, q)`, `rem_euclid(p, n)`, `eval(p, n)` and `gcd(p, q)` call the methods of
+/// the same names; `gcd` and `rem_euclid` only work over the integers. Nothing is allowed
+/// to reach a degree above `MAX_PARSE_DEGREE`, so a short expression like `x^3000000000`
+/// can't ask for any amount of memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expr {
+    root: Node,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    kind: NodeKind,
+    position: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NodeKind {
+    Num(isize),
+    Var,
+    Neg(Box<Node>),
+    Add(Box<Node>, Box<Node>),
+    Sub(Box<Node>, Box<Node>),
+    Mul(Box<Node>, Box<Node>),
+    Pow(Box<Node>, u32),
+    Call(Function, Vec<(Node, usize)>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Derivative,
+    Compose,
+    RemEuclid,
+    Eval,
+    Gcd,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "derivative" => Some(Self::Derivative),
+            "compose" => Some(Self::Compose),
+            "rem_euclid" => Some(Self::RemEuclid),
+            "eval" => Some(Self::Eval),
+            "gcd" => Some(Self::Gcd),
+            _ => None,
+        }
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Self::Derivative => 1,
+            Self::Compose | Self::RemEuclid | Self::Eval | Self::Gcd => 2,
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ExprError;
+
+    /// Parses an expression in the variable `x`; see `Expr::parse()`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Expr {
+    /// Parses an expression in the variable `x`. Unknown names and functions, and calls
+    /// with the wrong number of arguments, are caught here rather than when evaluating.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::expr::{Expr, ExprErrorKind};
+    ///
+    /// assert!(Expr::parse("(x + 1)(x - 1) - 3x^2").is_ok());
+    ///
+    /// let err = Expr::parse("2x + y").unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::UnknownVariable(String::from("y")));
+    /// assert_eq!(err.position(), 5);
+    ///
+    /// let err = Expr::parse("compose(x^2)").unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::WrongArgumentCount { expected: 2, found: 1 });
+    /// assert_eq!(err.position(), 0);
+    ///
+    /// let err = Expr::parse("(x + 1").unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::UnexpectedEnd);
+    /// assert_eq!(err.position(), 6);
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ExprError> {
+        Self::parse_with_var(s, "x")
+    }
+
+    /// Parses an expression in the variable `var`, which has to be a word (letters, digits
+    /// and underscores, not starting with a digit) other than one of the function names.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, expr::Expr};
+    ///
+    /// let expr = Expr::parse_with_var("(t + 1)^2", "t").unwrap();
+    /// assert_eq!(expr.eval(), Ok(poly![1, 2, 1]));
+    /// ```
+    pub fn parse_with_var(s: &str, var: &str) -> Result<Self, ExprError> {
+        let is_word = var.chars().next().is_some_and(is_word_start)
+            && var.chars().all(is_word_char)
+            && Function::from_name(var).is_none();
+        if !is_word {
+            return Err(ExprError::new(ExprErrorKind::InvalidVariable, 0));
+        }
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+            s,
+            var,
+        };
+        let root = parser.sum()?;
+        match parser.peek() {
+            Some(&(_, at)) => Err(parser.unexpected(at)),
+            None => Ok(Self { root }),
+        }
+    }
+
+    /// Evaluates the expression over the integers, reporting an overflow if any coefficient
+    /// along the way doesn't fit in an `isize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, expr::{Expr, ExprErrorKind}};
+    ///
+    /// let expr: Expr = "derivative(compose(x^2, x - 1)) + rem_euclid(7x + 3, 5)".parse().unwrap();
+    /// assert_eq!(expr.eval(), Ok(poly![1, 4]));
+    ///
+    /// let err = "(x + 2)^70".parse::<Expr>().unwrap().eval().unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::Overflow);
+    /// assert_eq!(err.position(), 7);
+    ///
+    /// let err = "rem_euclid(x, 2 - 2)".parse::<Expr>().unwrap().eval().unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::DivisionByZero);
+    /// assert_eq!(err.position(), 14);
+    ///
+    /// let expr: Expr = "derivative(gcd(x^2 - 1, x^2 + 2x + 1)) + eval(x^2 + 1, 3)".parse().unwrap();
+    /// assert_eq!(expr.eval(), Ok(poly![11]));
+    ///
+    /// let err = "1 + x^3000000000".parse::<Expr>().unwrap().eval().unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::DegreeTooLarge);
+    /// assert_eq!(err.position(), 5);
+    /// let err = "compose(x^300, x^300 + 1)".parse::<Expr>().unwrap().eval().unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::DegreeTooLarge);
+    /// assert_eq!(err.position(), 0);
+    /// ```
+    pub fn eval(&self) -> Result<Polynomial, ExprError> {
+        self.root.eval(Arith::Integers)
+    }
+
+    /// Evaluates the expression modulo `m`, reducing as it goes so that, unlike reducing the
+    /// result of `eval()`, it only overflows if `m` itself is too big. The coefficients of
+    /// the result are in the range `0..m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, expr::{Expr, ExprErrorKind}};
+    ///
+    /// let expr: Expr = "(x + 2)^70".parse().unwrap();
+    /// assert_eq!(expr.eval_mod(7), Ok(poly![2, 0, 0, 0, 0, 0, 0, 1].pow_mod(10, 7)));
+    ///
+    /// let expr: Expr = "(x + 1)^5 * (x - 2)".parse().unwrap();
+    /// assert_eq!(expr.eval_mod(7), Ok(poly![5, 5, 6, 4, 0, 3, 1]));
+    /// assert_eq!("eval(x^2 + 1, 3)".parse::<Expr>().unwrap().eval_mod(7), Ok(poly![3]));
+    ///
+    /// // reducing 7x + 3 modulo 5 and then modulo 3 isn't the same as reducing it modulo 3
+    /// let err = "rem_euclid(7x + 3, 5)".parse::<Expr>().unwrap().eval_mod(3).unwrap_err();
+    /// assert_eq!(err.kind(), &ExprErrorKind::NotModular);
+    /// ```
+    pub fn eval_mod(&self, m: isize) -> Result<Polynomial, ExprError> {
+        if m < 2 {
+            return Err(ExprError::new(ExprErrorKind::InvalidModulus, 0));
+        }
+        self.root.eval(Arith::Mod(m as i128))
+    }
+}
+
+/// Parses and evaluates an expression in `x` over the integers; see `Expr`.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, expr};
+///
+/// assert_eq!(expr::eval("(x + 1)(x - 1) - 3x^2"), Ok(poly![-1, 0, -2]));
+/// assert!(expr::eval("x +").is_err());
+/// ```
+pub fn eval(s: &str) -> Result<Polynomial, ExprError> {
+    Expr::parse(s)?.eval()
+}
+
+impl Node {
+    fn eval(&self, arith: Arith) -> Result<Polynomial, ExprError> {
+        let overflow = || ExprError::new(ExprErrorKind::Overflow, self.position);
+        match &self.kind {
+            NodeKind::Num(n) => arith.poly(vec![*n as i128]).ok_or_else(overflow),
+            NodeKind::Var => Ok(Polynomial::new(vec![0, 1])),
+            NodeKind::Neg(a) => {
+                let a = a.eval(arith)?;
+                arith
+                    .poly(a.coeffs.iter().map(|&c| -(c as i128)).collect())
+                    .ok_or_else(overflow)
+            }
+            NodeKind::Add(a, b) => arith
+                .add(&a.eval(arith)?, &b.eval(arith)?, 1)
+                .ok_or_else(overflow),
+            NodeKind::Sub(a, b) => arith
+                .add(&a.eval(arith)?, &b.eval(arith)?, -1)
+                .ok_or_else(overflow),
+            NodeKind::Mul(a, b) => {
+                let (a, b) = (a.eval(arith)?, b.eval(arith)?);
+                self.check_degree(degree(&a) + degree(&b))?;
+                arith.mul(&a, &b).ok_or_else(overflow)
+            }
+            NodeKind::Pow(a, e) => {
+                let a = a.eval(arith)?;
+                self.check_degree(degree(&a) * *e as u128)?;
+                arith.pow(&a, *e).ok_or_else(overflow)
+            }
+            NodeKind::Call(function, args) => {
+                let values = args
+                    .iter()
+                    .map(|(arg, _)| arg.eval(arith))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match function {
+                    Function::Derivative => arith.derivative(&values[0]).ok_or_else(overflow),
+                    Function::Compose => {
+                        self.check_degree(degree(&values[0]) * degree(&values[1]))?;
+                        arith.compose(&values[0], &values[1]).ok_or_else(overflow)
+                    }
+                    Function::RemEuclid => {
+                        // the coefficients have already been reduced modulo m, which
+                        // reducing modulo n doesn't commute with
+                        if let Arith::Mod(_) = arith {
+                            return Err(ExprError::new(ExprErrorKind::NotModular, self.position));
+                        }
+                        let n = constant_arg(&values[1], args[1].1)?;
+                        if n == 0 {
+                            return Err(ExprError::new(ExprErrorKind::DivisionByZero, args[1].1));
+                        }
+                        let coeffs = values[0]
+                            .coeffs
+                            .iter()
+                            .map(|c| c.checked_rem_euclid(n).map(|c| c as i128))
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(overflow)?;
+                        arith.poly(coeffs).ok_or_else(overflow)
+                    }
+                    Function::Eval => {
+                        let x = constant_arg(&values[1], args[1].1)?;
+                        arith.eval(&values[0], x).ok_or_else(overflow)
+                    }
+                    Function::Gcd => match arith {
+                        Arith::Integers => values[0].try_gcd(&values[1]).map_err(|_| overflow()),
+                        Arith::Mod(_) => {
+                            Err(ExprError::new(ExprErrorKind::NotModular, self.position))
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    /// Gives an error at this node if `degree` is above `MAX_PARSE_DEGREE`; checked before
+    /// every operation that raises the degree, so nothing that big is ever built.
+    fn check_degree(&self, degree: u128) -> Result<(), ExprError> {
+        if degree > MAX_PARSE_DEGREE as u128 {
+            Err(ExprError::new(ExprErrorKind::DegreeTooLarge, self.position))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Gives the degree of `p`, taking that of zero to be 0, in a type that any product of two
+/// of them fits in.
+fn degree(p: &Polynomial) -> u128 {
+    p.coeffs.len().saturating_sub(1) as u128
+}
+
+/// Gives the value of an argument that has to be a constant, which starts at `at`.
+fn constant_arg(value: &Polynomial, at: usize) -> Result<isize, ExprError> {
+    match value.coeffs.as_slice() {
+        [] => Ok(0),
+        [n] => Ok(*n),
+        _ => Err(ExprError::new(ExprErrorKind::NonConstantArgument, at)),
+    }
+}
+
+/// Where the arithmetic happens: the integers, where anything that doesn't fit in an
+/// `isize` is an overflow, or the integers modulo some `m`. Every operation works on
+/// `i128`s and then brings the result back into range, giving `None` on overflow.
+#[derive(Clone, Copy)]
+enum Arith {
+    Integers,
+    Mod(i128),
+}
+
+impl Arith {
+    fn norm(self, c: i128) -> Option<isize> {
+        match self {
+            Self::Integers => isize::try_from(c).ok(),
+            Self::Mod(m) => Some(c.rem_euclid(m) as isize),
+        }
+    }
+
+    fn poly(self, coeffs: Vec<i128>) -> Option<Polynomial> {
+        coeffs
+            .into_iter()
+            .map(|c| self.norm(c))
+            .collect::<Option<Vec<_>>>()
+            .map(Polynomial::new)
+    }
+
+    /// Adds `sign` times `b` to `a`.
+    fn add(self, a: &Polynomial, b: &Polynomial, sign: i128) -> Option<Polynomial> {
+        let len = a.coeffs.len().max(b.coeffs.len());
+        let coeff = |p: &Polynomial, i: usize| p.coeffs.get(i).copied().unwrap_or(0) as i128;
+        self.poly((0..len).map(|i| coeff(a, i) + sign * coeff(b, i)).collect())
+    }
+
+    fn mul(self, a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+        if a.is_zero() || b.is_zero() {
+            return Some(Polynomial::zero());
+        }
+        let mut coeffs = vec![0i128; a.coeffs.len() + b.coeffs.len() - 1];
+        for (i, &x) in a.coeffs.iter().enumerate() {
+            for (j, &y) in b.coeffs.iter().enumerate() {
+                let sum = coeffs[i + j].checked_add(x as i128 * y as i128)?;
+                coeffs[i + j] = match self {
+                    Self::Integers => sum,
+                    Self::Mod(m) => sum.rem_euclid(m),
+                };
+            }
+        }
+        self.poly(coeffs)
+    }
+
+    fn pow(self, base: &Polynomial, mut e: u32) -> Option<Polynomial> {
+        let mut acc = self.poly(vec![1])?;
+        let mut base = base.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.mul(&acc, &base)?;
+            }
+            e >>= 1;
+            // squaring once more than needed could overflow for no reason
+            if e > 0 {
+                base = self.mul(&base, &base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    fn derivative(self, a: &Polynomial) -> Option<Polynomial> {
+        let coeffs = a.coeffs.iter().enumerate().skip(1);
+        self.poly(coeffs.map(|(i, &c)| i as i128 * c as i128).collect())
+    }
+
+    /// Plugs `x` into `a` by Horner's scheme, giving a constant polynomial.
+    fn eval(self, a: &Polynomial, x: isize) -> Option<Polynomial> {
+        let mut acc = 0;
+        for &c in a.coeffs.iter().rev() {
+            let sum = (acc as i128)
+                .checked_mul(x as i128)?
+                .checked_add(c as i128)?;
+            acc = self.norm(sum)?;
+        }
+        self.poly(vec![acc as i128])
+    }
+
+    /// Substitutes `inner` into `outer` by Horner's scheme.
+    fn compose(self, outer: &Polynomial, inner: &Polynomial) -> Option<Polynomial> {
+        let mut acc = Polynomial::zero();
+        for &c in outer.coeffs.iter().rev() {
+            acc = self.mul(&acc, inner)?;
+            acc = self.add(&acc, &Polynomial::constant(c), 1)?;
+        }
+        Some(acc)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Num(isize),
+    Word(String),
+    Plus,
+    Minus,
+    Star,
+    Caret,
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn is_word_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn tokenize(s: &str) -> Result<Vec<(Token, usize)>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((at, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            c if is_word_char(c) => {
+                let mut end = at + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !is_word_char(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &s[at..end];
+                if is_word_start(c) {
+                    Token::Word(String::from(word))
+                } else if let Some(i) = word.find(|c: char| !c.is_ascii_digit()) {
+                    // a number runs straight into a name, as in 2x; split them up
+                    let n = parse_num(&word[..i], at)?;
+                    tokens.push((Token::Num(n), at));
+                    let rest = &word[i..];
+                    if !rest.starts_with(is_word_start) {
+                        let c = rest.chars().next().unwrap();
+                        return Err(ExprError::new(ExprErrorKind::UnexpectedChar(c), at + i));
+                    }
+                    tokens.push((Token::Word(String::from(rest)), at + i));
+                    continue;
+                } else {
+                    Token::Num(parse_num(word, at)?)
+                }
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => {
+                if let Some(&(_, '*')) = chars.peek() {
+                    chars.next();
+                    Token::Caret
+                } else {
+                    Token::Star
+                }
+            }
+            '^' => Token::Caret,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            c => return Err(ExprError::new(ExprErrorKind::UnexpectedChar(c), at)),
+        };
+        tokens.push((token, at));
+    }
+    Ok(tokens)
+}
+
+fn parse_num(digits: &str, at: usize) -> Result<isize, ExprError> {
+    digits
+        .parse()
+        .map_err(|_| ExprError::new(ExprErrorKind::NumberOverflow, at))
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    s: &'a str,
+    var: &'a str,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<(Token, usize), ExprError> {
+        match self.tokens.get(self.pos) {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token.clone())
+            }
+            None => Err(ExprError::new(ExprErrorKind::UnexpectedEnd, self.s.len())),
+        }
+    }
+
+    /// The error for a token that doesn't belong where it is, which names its first
+    /// character.
+    fn unexpected(&self, at: usize) -> ExprError {
+        let c = self.s[at..].chars().next().unwrap_or_default();
+        ExprError::new(ExprErrorKind::UnexpectedChar(c), at)
+    }
+
+    fn sum(&mut self) -> Result<Node, ExprError> {
+        let mut acc = self.product()?;
+        loop {
+            let kind: fn(Box<Node>, Box<Node>) -> NodeKind = match self.peek() {
+                Some((Token::Plus, _)) => NodeKind::Add,
+                Some((Token::Minus, _)) => NodeKind::Sub,
+                _ => return Ok(acc),
+            };
+            let (_, position) = self.next()?;
+            let rhs = self.product()?;
+            acc = Node {
+                kind: kind(Box::new(acc), Box::new(rhs)),
+                position,
+            };
+        }
+    }
+
+    fn product(&mut self) -> Result<Node, ExprError> {
+        let mut acc = self.unary()?;
+        loop {
+            let (rhs, position) = match self.peek() {
+                Some(&(Token::Star, at)) => {
+                    self.pos += 1;
+                    (self.unary()?, at)
+                }
+                // juxtaposition, like 2x or (x + 1)(x - 1), is multiplication too
+                Some(&(Token::Num(_), at))
+                | Some(&(Token::Word(_), at))
+                | Some(&(Token::LParen, at)) => (self.power()?, at),
+                _ => return Ok(acc),
+            };
+            acc = Node {
+                kind: NodeKind::Mul(Box::new(acc), Box::new(rhs)),
+                position,
+            };
+        }
+    }
+
+    fn unary(&mut self) -> Result<Node, ExprError> {
+        match self.peek() {
+            Some(&(Token::Minus, position)) => {
+                self.pos += 1;
+                let inner = self.unary()?;
+                Ok(Node {
+                    kind: NodeKind::Neg(Box::new(inner)),
+                    position,
+                })
+            }
+            Some((Token::Plus, _)) => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Node, ExprError> {
+        let base = self.atom()?;
+        let position = match self.peek() {
+            Some(&(Token::Caret, at)) => at,
+            _ => return Ok(base),
+        };
+        self.pos += 1;
+        match self.next()? {
+            (Token::Num(e), at) => {
+                let e = u32::try_from(e)
+                    .map_err(|_| ExprError::new(ExprErrorKind::ExponentOverflow, at))?;
+                Ok(Node {
+                    kind: NodeKind::Pow(Box::new(base), e),
+                    position,
+                })
+            }
+            (_, at) => Err(self.unexpected(at)),
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, ExprError> {
+        let (token, position) = self.next()?;
+        let kind = match token {
+            Token::Num(n) => NodeKind::Num(n),
+            Token::Word(word) if word == self.var => NodeKind::Var,
+            Token::Word(word) => {
+                if let Some((Token::LParen, _)) = self.peek() {
+                    self.pos += 1;
+                } else {
+                    return Err(ExprError::new(
+                        ExprErrorKind::UnknownVariable(word),
+                        position,
+                    ));
+                }
+                let function = Function::from_name(&word).ok_or_else(|| {
+                    ExprError::new(ExprErrorKind::UnknownFunction(word), position)
+                })?;
+                let mut args = Vec::new();
+                if let Some((Token::RParen, _)) = self.peek() {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        let start = self.peek().map_or(self.s.len(), |&(_, at)| at);
+                        args.push((self.sum()?, start));
+                        match self.next()? {
+                            (Token::Comma, _) => {}
+                            (Token::RParen, _) => break,
+                            (_, at) => return Err(self.unexpected(at)),
+                        }
+                    }
+                }
+                if args.len() != function.arity() {
+                    return Err(ExprError::new(
+                        ExprErrorKind::WrongArgumentCount {
+                            expected: function.arity(),
+                            found: args.len(),
+                        },
+                        position,
+                    ));
+                }
+                NodeKind::Call(function, args)
+            }
+            Token::LParen => {
+                let inner = self.sum()?;
+                match self.next()? {
+                    (Token::RParen, _) => return Ok(inner),
+                    (_, at) => return Err(self.unexpected(at)),
+                }
+            }
+            _ => return Err(self.unexpected(position)),
+        };
+        Ok(Node { kind, position })
+    }
+}
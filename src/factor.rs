@@ -0,0 +1,819 @@
+use std::convert::TryFrom;
+
+use crate::error::PolyError;
+use crate::Polynomial;
+
+/// Primes just below 2^61, 2^60 and 2^59 for `modular_gcd()`; products of residues
+/// modulo these fit comfortably in an `i128`.
+const GCD_PRIMES: [isize; 3] = [2305843009213693951, 1152921504606846883, 576460752303423433];
+
+/// The most pairs of divisors of the leading and constant coefficients that `factor()`
+/// tries as rational roots `b/a` of a single factor before giving up on it.
+pub const MAX_ROOT_CANDIDATES: usize = 1 << 20;
+
+/// The first twelve primes, which are enough Miller-Rabin bases to tell whether any
+/// 64-bit number is prime.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// A polynomial split up into a constant times a product of powers of nonconstant factors,
+/// as returned by `Polynomial::factor()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization {
+    /// The constant in front, which carries the content and the sign of the polynomial.
+    pub content: isize,
+    /// The nonconstant factors, each primitive with positive leading coefficient, along
+    /// with their multiplicities.
+    pub factors: Vec<(Polynomial, usize)>,
+}
+
+impl Factorization {
+    /// Multiplies the factorization back out.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![-6, 4, 6, -4];
+    /// assert_eq!(poly.factor().expand(), poly);
+    /// ```
+    pub fn expand(&self) -> Polynomial {
+        self.factors
+            .iter()
+            .fold(Polynomial::constant(self.content), |acc, (factor, mult)| {
+                acc * factor.pow(*mult as u32)
+            })
+    }
+}
+
+impl std::fmt::Display for Factorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.factors.is_empty() {
+            return write!(f, "{}", self.content);
+        }
+        match self.content {
+            1 => {}
+            -1 => write!(f, "-")?,
+            c => write!(f, "{} * ", c)?,
+        }
+        for (i, (factor, mult)) in self.factors.iter().enumerate() {
+            if i > 0 {
+                write!(f, " * ")?;
+            }
+            // a bare x doesn't need parentheses
+            if factor.coeffs == [0, 1] {
+                write!(f, "{}", factor)?;
+            } else {
+                write!(f, "({})", factor)?;
+            }
+            if *mult > 1 {
+                write!(f, "^{}", mult)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Polynomial {
+    /// Gives the content of the polynomial, i.e. the (nonnegative) greatest common divisor
+    /// of its coefficients. The content of zero is zero. If every coefficient is zero or
+    /// `isize::MIN`, the content is 2^63, which doesn't fit and comes out as `isize::MIN`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(poly![6, -4, 10].content(), 2);
+    /// assert_eq!(poly![-3].content(), 3);
+    /// assert_eq!(Polynomial::zero().content(), 0);
+    /// assert_eq!(poly![isize::MIN, -2].content(), 2);
+    /// ```
+    pub fn content(&self) -> isize {
+        self.coeffs.iter().fold(0, |acc, &c| Self::int_gcd(acc, c))
+    }
+
+    /// Gives the primitive part of the polynomial: the polynomial divided by its content,
+    /// with the sign flipped if need be so that the leading coefficient is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(poly![6, -4, 10].primitive_part(), poly![3, -2, 5]);
+    /// assert_eq!(poly![6, -4, -10].primitive_part(), poly![-3, 2, 5]);
+    /// ```
+    pub fn primitive_part(&self) -> Self {
+        match self.coeffs.last() {
+            None => Self::zero(),
+            Some(_) => {
+                let content = self.signed_content();
+                Self {
+                    coeffs: self.coeffs.iter().map(|&c| c / content).collect(),
+                }
+            }
+        }
+    }
+
+    /// Gives the content with the sign of the leading coefficient, or 0 for zero. A
+    /// content of 2^63 only comes from a leading coefficient of `isize::MIN`, so it wraps
+    /// around to the right answer.
+    pub(crate) fn signed_content(&self) -> isize {
+        match self.coeffs.last() {
+            Some(&lead) if lead < 0 => self.content().wrapping_neg(),
+            Some(_) => self.content(),
+            None => 0,
+        }
+    }
+
+    /// Gives the greatest common divisor of two polynomials in the integer polynomials: the
+    /// gcd of their contents times the gcd of their primitive parts, with positive leading
+    /// coefficient. The primitive parts' gcd is found modulo a large prime when possible,
+    /// and otherwise with the primitive polynomial remainder sequence, which keeps all the
+    /// arithmetic in the integers. Panics if that overflows; `try_gcd()` gives an error
+    /// instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let common = poly![-1, 2]; // 2x - 1
+    /// let a = &common * &poly![3, 1] * 4;
+    /// let b = &common * &poly![1, 0, 1] * 6;
+    /// assert_eq!(a.gcd(&b), &common * 2);
+    /// assert_eq!(poly![1, 1].gcd(&poly![-1, 1]), poly![1]);
+    /// assert_eq!(a.gcd(&Polynomial::zero()), a.primitive_part() * 4);
+    ///
+    /// // big coefficients whose gcd still fits
+    /// let common = poly![1 << 20, 1];
+    /// let a = &common * &poly![1, 1 << 40];
+    /// let b = &common * &poly![3, 1 << 40];
+    /// assert_eq!(a.gcd(&b), common);
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.try_gcd(other).expect("overflow in polynomial gcd")
+    }
+
+    /// Like `gcd()`, but gives an error rather than panicking if the remainder sequence, or
+    /// the gcd itself, doesn't fit in an `isize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let a = poly![-1, 0, 1] * poly![2, 3];
+    /// let b = poly![1, 1] * poly![5, 0, 7];
+    /// assert_eq!(a.try_gcd(&b), Ok(poly![1, 1]));
+    /// ```
+    pub fn try_gcd(&self, other: &Self) -> Result<Self, PolyError> {
+        let content = Self::int_gcd(self.content(), other.content());
+        let mut a = self.primitive_part();
+        let mut b = other.primitive_part();
+        if a.degree() < b.degree() {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if !b.is_zero() {
+            if let Some(gcd) = Self::modular_gcd(&a, &b) {
+                return gcd.checked_scale(content);
+            }
+        }
+        while !b.is_zero() {
+            let r = a.pseudo_rem(&b).ok_or(PolyError::Overflow)?;
+            a = b;
+            b = r.primitive_part();
+        }
+        a.primitive_part().checked_scale(content)
+    }
+
+    /// Multiplies every coefficient by `factor`, checking for overflow.
+    fn checked_scale(&self, factor: isize) -> Result<Self, PolyError> {
+        let coeffs = self.coeffs.iter().map(|&c| c.checked_mul(factor));
+        let coeffs: Option<Vec<isize>> = coeffs.collect();
+        coeffs.map(Self::new).ok_or(PolyError::Overflow)
+    }
+
+    /// Tries to find the gcd of two nonzero primitive polynomials from their gcd modulo a
+    /// large prime, which avoids the coefficient growth of the remainder sequence: the gcd
+    /// over the integers can't have higher degree than the one modulo `p` (for `p` not
+    /// dividing either leading coefficient), and a candidate lifted back to the integers
+    /// is checked by dividing. Gives `None` if no prime in `GCD_PRIMES` works out.
+    fn modular_gcd(a: &Self, b: &Self) -> Option<Self> {
+        let lead_a = a.coeffs[a.coeffs.len() - 1];
+        let lead_b = b.coeffs[b.coeffs.len() - 1];
+        // the gcd's leading coefficient divides both of these, so scaling the monic gcd
+        // modulo p up by their gcd gives a multiple of the true gcd
+        let scale = Self::int_gcd(lead_a, lead_b) as i128;
+        for &p in GCD_PRIMES.iter() {
+            if lead_a % p == 0 || lead_b % p == 0 {
+                continue;
+            }
+            let gcd = Self::gcd_mod_p(a, b, p);
+            if gcd.len() == 1 {
+                return Some(Self::constant(1));
+            }
+            let half = p as i128 / 2;
+            let lifted = gcd.iter().map(|&c| {
+                let c = c * scale % p as i128;
+                isize::try_from(if c > half { c - p as i128 } else { c })
+            });
+            let candidate = match lifted.collect::<Result<Vec<isize>, _>>() {
+                Ok(coeffs) => Self::new(coeffs).primitive_part(),
+                Err(_) => continue,
+            };
+            if Self::divides_exactly(&candidate, a) && Self::divides_exactly(&candidate, b) {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
+    /// Checks whether `divisor` goes into `poly` exactly, by long division in `i128` with
+    /// every step checked: a wrongly lifted gcd candidate can have huge coefficients, and
+    /// dividing by it overflowing just means it isn't the gcd.
+    fn divides_exactly(divisor: &Self, poly: &Self) -> bool {
+        let ddeg = divisor.coeffs.len() - 1;
+        let lead = divisor.coeffs[ddeg] as i128;
+        let mut rem: Vec<i128> = poly.coeffs.iter().map(|&c| c as i128).collect();
+        while rem.len() > ddeg {
+            let top = rem.pop().unwrap();
+            if top % lead != 0 {
+                return false;
+            }
+            let q = top / lead;
+            let shift = rem.len() - ddeg;
+            for (i, &d) in divisor.coeffs[..ddeg].iter().enumerate() {
+                match q
+                    .checked_mul(d as i128)
+                    .and_then(|qd| rem[shift + i].checked_sub(qd))
+                {
+                    Some(c) => rem[shift + i] = c,
+                    None => return false,
+                }
+            }
+        }
+        rem.iter().all(|&c| c == 0)
+    }
+
+    /// Gives the monic gcd of two polynomials modulo `p`, as coefficients in increasing
+    /// order, for `p` not dividing the leading coefficient of `b`.
+    fn gcd_mod_p(a: &Self, b: &Self, p: isize) -> Vec<i128> {
+        let modulus = p as i128;
+        let reduce = |poly: &Self| {
+            let mut coeffs: Vec<i128> = poly
+                .coeffs
+                .iter()
+                .map(|&c| (c as i128).rem_euclid(modulus))
+                .collect();
+            while coeffs.last() == Some(&0) {
+                coeffs.pop();
+            }
+            coeffs
+        };
+        let monic = |coeffs: &mut Vec<i128>| {
+            let inv = Self::inv_mod_p(coeffs[coeffs.len() - 1] as isize, p) as i128;
+            for c in coeffs.iter_mut() {
+                *c = *c * inv % modulus;
+            }
+        };
+        let (mut r0, mut r1) = (reduce(a), reduce(b));
+        monic(&mut r1);
+        while !r1.is_empty() {
+            // reduce r0 modulo the monic r1, knocking out its top coefficient each time
+            while r0.len() >= r1.len() {
+                let top = r0.pop().unwrap();
+                let shift = r0.len() + 1 - r1.len();
+                for (i, &d) in r1[..r1.len() - 1].iter().enumerate() {
+                    r0[shift + i] = (r0[shift + i] - top * d).rem_euclid(modulus);
+                }
+                while r0.last() == Some(&0) {
+                    r0.pop();
+                }
+            }
+            if !r0.is_empty() {
+                monic(&mut r0);
+            }
+            std::mem::swap(&mut r0, &mut r1);
+        }
+        r0
+    }
+
+    /// Gives a remainder of `self` by `divisor` after scaling `self` up by enough of the
+    /// leading coefficient of `divisor` that the division works over the integers. Used
+    /// by gcd(), which only cares about the remainder up to a constant, so each step only
+    /// scales by as much as it needs to and the remainder's content is divided out as it
+    /// goes; the work is done in `i128` since the scaling can overflow `isize` long before
+    /// the primitive result would. Gives `None` if even that overflows.
+    fn pseudo_rem(&self, divisor: &Self) -> Option<Self> {
+        // the magnitudes of an isize and an i128 have a gcd that fits in an i128, unless
+        // both are i128::MIN or zero, in which case it wraps to i128::MIN; dividing by
+        // that is still exact
+        let gcd = |a: i128, b: i128| Self::u128_gcd(a.unsigned_abs(), b.unsigned_abs()) as i128;
+        let (lower, lead) = divisor.coeffs.split_at(divisor.coeffs.len() - 1);
+        let lead = lead[0] as i128;
+        let mut rem: Vec<i128> = self.coeffs.iter().map(|&c| c as i128).collect();
+        while rem.len() > lower.len() {
+            let shift = rem.len() - divisor.coeffs.len();
+            let top = rem.pop().unwrap();
+            // rem * (lead/g) - x^shift * divisor * (top/g) kills the top coefficient
+            let g = gcd(lead, top);
+            for c in rem.iter_mut() {
+                *c = c.checked_mul(lead / g)?;
+            }
+            for (i, &d) in lower.iter().enumerate() {
+                rem[shift + i] = rem[shift + i].checked_sub((d as i128).checked_mul(top / g)?)?;
+            }
+            while rem.last() == Some(&0) {
+                rem.pop();
+            }
+            let content = rem.iter().fold(0, |acc, &c| gcd(acc, c));
+            if content > 1 || content == i128::MIN {
+                for c in rem.iter_mut() {
+                    *c /= content;
+                }
+            }
+        }
+        let coeffs = rem.into_iter().map(|c| isize::try_from(c).ok());
+        coeffs.collect::<Option<Vec<isize>>>().map(Self::new)
+    }
+
+    /// Gives the distinct integer roots of the polynomial, in increasing order. (The zero
+    /// polynomial has every integer as a root, but this gives an empty list for it.)
+    ///
+    /// Any integer root divides the lowest nonzero coefficient, so only those divisors are
+    /// tried, each by synthetic division from the constant term up, which keeps all the
+    /// numbers involved no bigger than the coefficients. The divisors come from the
+    /// coefficient's prime factorization, found by Pollard's rho method, so this stays
+    /// quick even for coefficients near `isize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![0, -2, 1] * poly![3, 1] * poly![1, 2]; // x(x - 2)(x + 3)(2x + 1)
+    /// assert_eq!(poly.integer_roots(), vec![-3, 0, 2]);
+    /// assert_eq!(poly![1, 0, 1].integer_roots(), vec![]);
+    ///
+    /// // big constant terms, all the way down to isize::MIN
+    /// assert_eq!(poly![999999999999999989, 1].integer_roots(), vec![-999999999999999989]);
+    /// assert_eq!(poly![isize::MIN, -1].integer_roots(), vec![isize::MIN]);
+    /// assert_eq!(poly![isize::MIN, 0, 1].integer_roots(), vec![]);
+    /// ```
+    pub fn integer_roots(&self) -> Vec<isize> {
+        let low = match self.coeffs.iter().position(|&c| c != 0) {
+            Some(low) => low,
+            None => return Vec::new(),
+        };
+        let reduced = &self.coeffs[low..];
+        let mut roots = Vec::new();
+        if low > 0 {
+            roots.push(0);
+        }
+        for d in divisors(reduced[0]) {
+            for root in signed(d) {
+                if Self::divide_linear(reduced, 1, root).is_some() {
+                    roots.push(root);
+                }
+            }
+        }
+        roots.sort_unstable();
+        roots
+    }
+
+    /// Gives the distinct roots of the polynomial modulo a prime `p`, in increasing order
+    /// in the range `0..p`. (A polynomial whose coefficients are all multiples of `p` has
+    /// every residue as a root, but this gives an empty list for it.) Panics if `p` isn't
+    /// prime; `try_roots_mod()` gives an error instead.
+    ///
+    /// Rather than trying every residue, this takes the gcd with `x^p - x`, which is the
+    /// product of `x - r` over all residues `r`, and splits it into linear factors by
+    /// taking gcds with `(x + a)^((p - 1)/2) - 1` for `a = 0, 1, ...` in turn, so it stays
+    /// fast for large `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, 0, 1]; // x^2 + 1
+    /// assert_eq!(poly.roots_mod(5), vec![2, 3]);
+    /// assert_eq!(poly.roots_mod(7), vec![]);
+    ///
+    /// let p = 1_000_000_000_039;
+    /// assert_eq!(poly![-2, 0, 1].roots_mod(p), vec![55287985658, 944712014381]);
+    /// let poly = poly![-3, 1] * poly![1, 1] * poly![-1 << 30, 1];
+    /// assert_eq!(poly.roots_mod(p), vec![3, 1 << 30, p - 1]);
+    /// ```
+    pub fn roots_mod(&self, p: isize) -> Vec<isize> {
+        self.try_roots_mod(p).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `roots_mod()`, but gives an error rather than panicking if `p` isn't prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![1, 0, 1];
+    /// assert_eq!(poly.try_roots_mod(2), Ok(vec![1]));
+    /// assert_eq!(poly.try_roots_mod(4), Err(PolyError::NonPrimeModulus(4)));
+    /// assert_eq!(poly.try_roots_mod(0), Err(PolyError::ZeroModulus));
+    /// ```
+    pub fn try_roots_mod(&self, p: isize) -> Result<Vec<isize>, PolyError> {
+        if p == 0 {
+            return Err(PolyError::ZeroModulus);
+        }
+        if p < 0 || !Self::is_prime(p as usize) {
+            return Err(PolyError::NonPrimeModulus(p));
+        }
+        let modulus = p as i128;
+        let mut f: Vec<i128> = self
+            .coeffs
+            .iter()
+            .map(|&c| (c as i128).rem_euclid(modulus))
+            .collect();
+        trim(&mut f);
+        if f.len() < 2 {
+            return Ok(Vec::new());
+        }
+        // with no more residues than coefficients, trying them all is cheaper
+        if p as usize <= f.len() {
+            return Ok((0..p).filter(|&x| self.eval_mod(x, p) == 0).collect());
+        }
+        make_monic(&mut f, modulus);
+        // x^p - x, reduced modulo f
+        let mut h = pow_mod(vec![0, 1], p as u128, &f, modulus);
+        h.resize(h.len().max(2), 0);
+        h[1] = (h[1] - 1).rem_euclid(modulus);
+        trim(&mut h);
+        let mut roots = Vec::new();
+        split_roots(gcd_mod(f, h, modulus), modulus, &mut roots);
+        roots.sort_unstable();
+        Ok(roots.into_iter().map(|r| r as isize).collect())
+    }
+
+    /// Splits the polynomial into square-free factors using Yun's algorithm: gives
+    /// `[(f_1, 1), (f_2, 2), ...]`, leaving out constant `f_i`, where the primitive part of
+    /// the polynomial is `f_1 * f_2^2 * ...` and the `f_i` are square-free, pairwise
+    /// coprime, primitive and have positive leading coefficients.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, 1] * poly![-2, 1].pow(3) * 5; // 5(x + 1)(x - 2)^3
+    /// assert_eq!(
+    ///     poly.square_free_decomposition(),
+    ///     vec![(poly![1, 1], 1), (poly![-2, 1], 3)]
+    /// );
+    /// ```
+    pub fn square_free_decomposition(&self) -> Vec<(Self, usize)> {
+        let mut output = Vec::new();
+        if self.degree() < 1 {
+            return output;
+        }
+        let f = self.primitive_part();
+        let deriv = f.derivative();
+        let a = f.gcd(&deriv);
+        // all of these divisions are exact, and by primitive polynomials, so they can
+        // be done over the integers
+        let mut b = f.div_rem(&a).unwrap().0;
+        let c = deriv.div_rem(&a).unwrap().0;
+        let mut d = &c - &b.derivative();
+        let mut mult = 1;
+        while b.degree() > 0 {
+            let a = b.gcd(&d);
+            let a = a.primitive_part();
+            if a.degree() > 0 {
+                output.push((a.clone(), mult));
+            }
+            b = b.div_rem(&a).unwrap().0;
+            let c = d.div_rem(&a).unwrap().0;
+            d = &c - &b.derivative();
+            mult += 1;
+        }
+        output
+    }
+
+    /// Factors the polynomial as far as this crate knows how: pulls out the content (with
+    /// the sign of the leading coefficient), splits the rest into square-free parts, and
+    /// splits off every linear factor `ax - b` coming from a rational root `b/a`. Factors
+    /// of degree 2 or 3 which are left over are then irreducible, but higher-degree ones
+    /// may still factor further. So may a factor whose leading and constant coefficients
+    /// have more than `MAX_ROOT_CANDIDATES` pairs of divisors to try, or one that only
+    /// splits into factors with coefficients too big for an `isize`; these are left as is
+    /// rather than spending minutes on them.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![-1, 2].pow(2) * poly![1, 0, 1] * -3; // -3(2x - 1)^2(x^2 + 1)
+    /// let factorization = poly.factor();
+    /// assert_eq!(factorization.content, -3);
+    /// assert_eq!(factorization.factors, vec![(poly![-1, 2], 2), (poly![1, 0, 1], 1)]);
+    /// assert_eq!(factorization.to_string(), "-3 * (2x - 1)^2 * (x^2 + 1)");
+    ///
+    /// assert_eq!(poly![0, 0, 2, 2].factor().to_string(), "2 * x^2 * (x + 1)");
+    /// assert_eq!(poly![-6].factor().to_string(), "-6");
+    /// assert_eq!(Polynomial::zero().factor().expand(), Polynomial::zero());
+    ///
+    /// // coefficients near isize::MAX
+    /// let big = poly![1000000000000000003, 0, 999999999999999989];
+    /// assert_eq!(big.factor().factors, vec![(big.clone(), 1)]);
+    /// let split = poly![-999999999999999989, 1] * poly![3, 2];
+    /// assert_eq!(split.factor().factors, vec![(poly![-999999999999999989, 1], 1), (poly![3, 2], 1)]);
+    /// ```
+    pub fn factor(&self) -> Factorization {
+        let content = self.signed_content();
+        let mut factors = Vec::new();
+        for (mut part, mult) in self.square_free_decomposition() {
+            while part.degree() > 1 {
+                match part.linear_factor() {
+                    Some((linear, rest)) => {
+                        factors.push((linear, mult));
+                        part = rest;
+                    }
+                    None => break,
+                }
+            }
+            factors.push((part, mult));
+        }
+        factors.sort_by(|a, b| (a.0.degree(), &a.0.coeffs).cmp(&(b.0.degree(), &b.0.coeffs)));
+        Factorization { content, factors }
+    }
+
+    /// For a primitive polynomial, finds a rational root `b/a` (so `a` divides the leading
+    /// coefficient and `b` the constant) and returns `ax - b` along with the cofactor.
+    /// Gives up, returning `None`, past `MAX_ROOT_CANDIDATES` pairs of divisors.
+    fn linear_factor(&self) -> Option<(Self, Self)> {
+        let lead = self.coeffs[self.coeffs.len() - 1];
+        if self.coeffs[0] == 0 {
+            return Some((Self::new(vec![0, 1]), Self::new(self.coeffs[1..].to_vec())));
+        }
+        let leads = divisors(lead);
+        let consts = divisors(self.coeffs[0]);
+        if leads.len().saturating_mul(consts.len()) > MAX_ROOT_CANDIDATES {
+            return None;
+        }
+        // the leading coefficient is positive, so its divisors all fit; only a constant of
+        // isize::MIN has a divisor that doesn't, and ax - b can't be written for that one
+        for a in leads.into_iter().map(|a| a as isize) {
+            for b in consts.iter().filter_map(|&b| isize::try_from(b).ok()) {
+                if Self::int_gcd(a, b) != 1 {
+                    continue;
+                }
+                for &b in &[b, -b] {
+                    if let Some(quot) = Self::divide_linear(&self.coeffs, a, b) {
+                        return Some((Self::new(vec![-b, a]), Self::new(quot)));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Divides the polynomial with coefficients `coeffs` by `ax - b`, for nonzero `b`, from
+    /// the bottom up, giving the quotient if the division is exact and it fits.
+    fn divide_linear(coeffs: &[isize], a: isize, b: isize) -> Option<Vec<isize>> {
+        // if p = (ax - b)q, then p_0 = -b q_0 and p_i = a q_{i-1} - b q_i, so we can find
+        // q_i = (a q_{i-1} - p_i)/b as long as the divisions are exact, and then the top
+        // coefficient of p has to be a times the top coefficient of q; each step is done
+        // in i128, where it can't overflow, and only q has to fit in an isize
+        let (a, b) = (a as i128, b as i128);
+        let mut quot = Vec::with_capacity(coeffs.len() - 1);
+        let mut acc: isize = 0;
+        for &coeff in &coeffs[..coeffs.len() - 1] {
+            let diff = a * acc as i128 - coeff as i128;
+            if diff % b != 0 {
+                return None;
+            }
+            acc = isize::try_from(diff / b).ok()?;
+            quot.push(acc);
+        }
+        if a * acc as i128 == coeffs[coeffs.len() - 1] as i128 {
+            Some(quot)
+        } else {
+            None
+        }
+    }
+}
+
+/// Gives the positive divisors of a nonzero `n`, in increasing order, built up from its
+/// prime factorization. They're `u64`s since `isize::MIN` has 2^63 as a divisor.
+fn divisors(n: isize) -> Vec<u64> {
+    let mut primes = prime_factors(n.unsigned_abs() as u64);
+    primes.sort_unstable();
+    let mut divisors = vec![1];
+    // each prime power p^k in n multiplies every divisor so far by p, p^2, ..., p^k
+    for run in primes.chunk_by(|p, q| p == q) {
+        let count = divisors.len();
+        let mut power = 1;
+        for &p in run {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Gives `d` and `-d`, as far as they fit in an `isize`.
+fn signed(d: u64) -> impl Iterator<Item = isize> {
+    let d = d as i128;
+    IntoIterator::into_iter([d, -d]).filter_map(|d| isize::try_from(d).ok())
+}
+
+/// Gives the prime factors of `n`, with repeats, in no particular order: small ones by
+/// trial division, and the rest by Pollard's rho method.
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut primes = Vec::new();
+    for &p in SMALL_PRIMES.iter() {
+        while n % p == 0 {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut composites = vec![n];
+    while let Some(m) = composites.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime_u64(m) {
+            primes.push(m);
+        } else {
+            let d = pollard_rho(m);
+            composites.push(d);
+            composites.push(m / d);
+        }
+    }
+    primes
+}
+
+fn mul_mod_u64(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+/// Checks whether `n` is prime by the Miller-Rabin test, which with `SMALL_PRIMES` as
+/// bases is exact for every 64-bit `n`.
+fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in SMALL_PRIMES.iter() {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    'bases: for &a in SMALL_PRIMES.iter() {
+        let mut x = 1;
+        let (mut base, mut e) = (a, d);
+        while e > 0 {
+            if e & 1 == 1 {
+                x = mul_mod_u64(x, base, n);
+            }
+            base = mul_mod_u64(base, base, n);
+            e >>= 1;
+        }
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Finds a nontrivial factor of the odd composite `n` by Pollard's rho method, following
+/// `x -> x^2 + c` around until it cycles modulo some factor, and trying the next `c` if
+/// it cycles modulo all of `n` at once.
+fn pollard_rho(n: u64) -> u64 {
+    let mut c = 1;
+    loop {
+        let step = |x: u64| (mul_mod_u64(x, x, n) + c) % n;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = step(x);
+            y = step(step(y));
+            d = Polynomial::u128_gcd(x.abs_diff(y) as u128, n as u128) as u64;
+        }
+        if d != n {
+            return d;
+        }
+        c += 1;
+    }
+}
+
+// Arithmetic on polynomials modulo a prime, for `try_roots_mod()`: each is a vector of
+// residues in increasing order with no trailing zeros, and every product of two residues
+// fits in an `i128`.
+
+fn trim(poly: &mut Vec<i128>) {
+    while poly.last() == Some(&0) {
+        poly.pop();
+    }
+}
+
+fn make_monic(poly: &mut [i128], modulus: i128) {
+    if let Some(&lead) = poly.last() {
+        let inv = Polynomial::inv_mod_p(lead as isize, modulus as isize) as i128;
+        for c in poly.iter_mut() {
+            *c = *c * inv % modulus;
+        }
+    }
+}
+
+/// Divides `poly` by the monic `divisor`, giving the quotient and remainder.
+fn div_rem_mod(poly: &[i128], divisor: &[i128], modulus: i128) -> (Vec<i128>, Vec<i128>) {
+    let ddeg = divisor.len() - 1;
+    let mut rem = poly.to_vec();
+    let mut quot = vec![0; rem.len().saturating_sub(ddeg)];
+    while rem.len() > ddeg {
+        let top = rem.pop().unwrap();
+        let shift = rem.len() - ddeg;
+        quot[shift] = top;
+        for (i, &d) in divisor[..ddeg].iter().enumerate() {
+            rem[shift + i] = (rem[shift + i] - top * d).rem_euclid(modulus);
+        }
+    }
+    trim(&mut quot);
+    trim(&mut rem);
+    (quot, rem)
+}
+
+/// Multiplies `a` and `b` modulo the monic `m`.
+fn mul_mod(a: &[i128], b: &[i128], m: &[i128], modulus: i128) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] = (product[i + j] + x * y) % modulus;
+        }
+    }
+    trim(&mut product);
+    div_rem_mod(&product, m, modulus).1
+}
+
+/// Raises `base` to the power `e` modulo the monic `m`, by repeated squaring.
+fn pow_mod(base: Vec<i128>, mut e: u128, m: &[i128], modulus: i128) -> Vec<i128> {
+    let mut acc = div_rem_mod(&[1], m, modulus).1;
+    let mut base = div_rem_mod(&base, m, modulus).1;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = mul_mod(&acc, &base, m, modulus);
+        }
+        e >>= 1;
+        if e > 0 {
+            base = mul_mod(&base, &base, m, modulus);
+        }
+    }
+    acc
+}
+
+/// Gives the monic gcd of `a` and `b`.
+fn gcd_mod(mut a: Vec<i128>, mut b: Vec<i128>, modulus: i128) -> Vec<i128> {
+    while !b.is_empty() {
+        make_monic(&mut b, modulus);
+        let (_, rem) = div_rem_mod(&a, &b, modulus);
+        a = std::mem::replace(&mut b, rem);
+    }
+    make_monic(&mut a, modulus);
+    a
+}
+
+/// Finds the roots of a monic product of distinct linear factors, pushing them onto
+/// `roots`. Half of the residues `r + a` are squares, the roots of `(x + a)^((p - 1)/2)
+/// - 1`, so for most `a` the gcd with that splits the factors up.
+fn split_roots(g: Vec<i128>, modulus: i128, roots: &mut Vec<i128>) {
+    match g.len() {
+        0 | 1 => return,
+        2 => {
+            roots.push((modulus - g[0]) % modulus);
+            return;
+        }
+        _ => {}
+    }
+    for a in 0..modulus {
+        let mut h = pow_mod(vec![a, 1], (modulus as u128 - 1) / 2, &g, modulus);
+        h.resize(h.len().max(1), 0);
+        h[0] = (h[0] - 1).rem_euclid(modulus);
+        trim(&mut h);
+        let d = gcd_mod(g.clone(), h, modulus);
+        if d.len() > 1 && d.len() < g.len() {
+            let (rest, _) = div_rem_mod(&g, &d, modulus);
+            split_roots(d, modulus, roots);
+            split_roots(rest, modulus, roots);
+            return;
+        }
+    }
+}
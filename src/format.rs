@@ -0,0 +1,389 @@
+use std::fmt::{self, Display, Write};
+
+use crate::Polynomial;
+
+/// The order in which `PolyFormatter` writes the terms of a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Constant term first, like `1 + 2x + 3x^2`.
+    Ascending,
+    /// Highest power first, like `3x^2 + 2x + 1`; this is what `Display` does.
+    Descending,
+}
+
+/// A builder for customized ways of writing out polynomials. The default settings give
+/// exactly what `Display` writes; each method changes one setting, and `format()` then
+/// wraps a polynomial in something that implements `Display` with those settings.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, format::{Order, PolyFormatter}};
+///
+/// let poly = poly![-5, 0, 2, -1]; // -x^3 + 2x^2 - 5
+/// assert_eq!(PolyFormatter::new().format(&poly).to_string(), poly.to_string());
+///
+/// let fancy = PolyFormatter::new().var("t").superscripts(true);
+/// assert_eq!(fancy.format(&poly).to_string(), "-t³ + 2t² - 5");
+///
+/// let plain = PolyFormatter::new()
+///     .order(Order::Ascending)
+///     .explicit_mul(true)
+///     .spacing(false)
+///     .show_zeros(true);
+/// assert_eq!(plain.format(&poly).to_string(), "-5+0*x+2*x^2-x^3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolyFormatter {
+    var: String,
+    order: Order,
+    superscripts: bool,
+    explicit_mul: bool,
+    spacing: bool,
+    show_zeros: bool,
+    power_op: &'static str,
+}
+
+impl Default for PolyFormatter {
+    fn default() -> Self {
+        Self {
+            var: String::from("x"),
+            order: Order::Descending,
+            superscripts: false,
+            explicit_mul: false,
+            spacing: true,
+            show_zeros: false,
+            power_op: "^",
+        }
+    }
+}
+
+impl PolyFormatter {
+    /// Creates a formatter with the default settings, which match `Display`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the variable (default `x`).
+    pub fn var(mut self, var: &str) -> Self {
+        self.var = String::from(var);
+        self
+    }
+
+    /// Sets the order the terms are written in (default `Order::Descending`).
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets whether powers are written as unicode superscripts, like `x²`, rather than
+    /// like `x^2` (default `false`).
+    pub fn superscripts(mut self, superscripts: bool) -> Self {
+        self.superscripts = superscripts;
+        self
+    }
+
+    /// Sets whether a `*` is written between coefficients and the variable, like `3*x`
+    /// (default `false`).
+    pub fn explicit_mul(mut self, explicit_mul: bool) -> Self {
+        self.explicit_mul = explicit_mul;
+        self
+    }
+
+    /// Sets whether the `+` and `-` between terms have spaces around them (default `true`).
+    pub fn spacing(mut self, spacing: bool) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether terms with zero coefficients below the leading term are written out,
+    /// like `x^2 + 0x + 1` (default `false`).
+    pub fn show_zeros(mut self, show_zeros: bool) -> Self {
+        self.show_zeros = show_zeros;
+        self
+    }
+
+    /// Sets the token written between the variable and its power when not using
+    /// superscripts (default `^`).
+    pub(crate) fn power_op(mut self, power_op: &'static str) -> Self {
+        self.power_op = power_op;
+        self
+    }
+
+    /// Wraps `poly` in something which writes it out with this formatter's settings.
+    pub fn format<'a>(&'a self, poly: &'a Polynomial) -> Formatted<'a> {
+        Formatted {
+            formatter: self,
+            poly,
+        }
+    }
+
+    fn write_power(&self, f: &mut fmt::Formatter<'_>, n: usize) -> fmt::Result {
+        if self.superscripts {
+            for digit in n.to_string().chars() {
+                f.write_char(match digit {
+                    '0' => '⁰',
+                    '1' => '¹',
+                    '2' => '²',
+                    '3' => '³',
+                    '4' => '⁴',
+                    '5' => '⁵',
+                    '6' => '⁶',
+                    '7' => '⁷',
+                    '8' => '⁸',
+                    _ => '⁹',
+                })?;
+            }
+            Ok(())
+        } else {
+            write!(f, "{}{}", self.power_op, n)
+        }
+    }
+
+    /// Writes out a polynomial given as its `(power, coefficient)` terms in ascending order
+    /// of power, as for `Display`; shared by the dense and sparse representations.
+    pub(crate) fn write_terms<I>(&self, f: &mut fmt::Formatter<'_>, terms: I) -> fmt::Result
+    where
+        I: DoubleEndedIterator<Item = (usize, isize)>,
+    {
+        let terms: Box<dyn Iterator<Item = (usize, isize)>> = match self.order {
+            Order::Ascending => Box::new(terms),
+            Order::Descending => Box::new(terms.rev()),
+        };
+        let mut terms = terms.filter(|&(_, i)| i != 0 || self.show_zeros).peekable();
+        // no terms at all means the zero polynomial
+        if terms.peek().is_none() {
+            return write!(f, "0");
+        }
+        let signs = if self.spacing { SPACED } else { UNSPACED };
+        let terms = terms.map(|(n, i)| Term::new(i, n == 0, n));
+        write_sum(f, signs, terms, |f, coeff, n| {
+            if let Some(abs) = coeff {
+                write!(f, "{}", abs)?;
+                if n > 0 && self.explicit_mul {
+                    write!(f, "*")?;
+                }
+            }
+            if n > 0 {
+                write!(f, "{}", self.var)?;
+            }
+            if n > 1 {
+                self.write_power(f, n)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The signs `write_sum()` writes in front of the terms of a sum.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Signs {
+    /// Written before the first term, if it's negative.
+    pub(crate) leading_minus: &'static str,
+    /// Written between terms, before a positive one.
+    pub(crate) plus: &'static str,
+    /// Written between terms, before a negative one.
+    pub(crate) minus: &'static str,
+}
+
+/// Signs as `Display` writes them, like `x - 1`.
+pub(crate) const SPACED: Signs = Signs {
+    leading_minus: "-",
+    plus: " + ",
+    minus: " - ",
+};
+
+/// Signs without spaces around them, like `x-1`.
+pub(crate) const UNSPACED: Signs = Signs {
+    leading_minus: "-",
+    plus: "+",
+    minus: "-",
+};
+
+/// A term for `write_sum()`: the sign and magnitude of its coefficient, whether it's a
+/// constant, and whatever else is needed to write out the rest of it (usually its power).
+pub(crate) struct Term<T> {
+    negative: bool,
+    abs: usize,
+    constant: bool,
+    rest: T,
+}
+
+impl<T> Term<T> {
+    pub(crate) fn new(coeff: isize, constant: bool, rest: T) -> Self {
+        Self {
+            negative: coeff < 0,
+            abs: coeff.unsigned_abs(),
+            constant,
+            rest,
+        }
+    }
+}
+
+/// Writes out `terms` as a sum, in the order given, taking care of the signs and of
+/// leaving out coefficients of 1. For each term, `render` gets the coefficient's magnitude
+/// if it should be written, along with the term's `rest`, and writes the term without its
+/// sign. Writes nothing at all if there are no terms; it's up to the caller to skip zero
+/// coefficients, and to write the zero polynomial however it likes.
+pub(crate) fn write_sum<W, T, I, F>(
+    out: &mut W,
+    signs: Signs,
+    terms: I,
+    mut render: F,
+) -> fmt::Result
+where
+    W: Write + ?Sized,
+    I: IntoIterator<Item = Term<T>>,
+    F: FnMut(&mut W, Option<usize>, T) -> fmt::Result,
+{
+    // this flag is "have we written a term yet", so we know whether to write
+    // plus/minus signs as operations rather than as prefixes
+    let mut plus_flag = false;
+    for term in terms {
+        match (plus_flag, term.negative) {
+            (false, true) => out.write_str(signs.leading_minus)?,
+            (false, false) => {}
+            (true, true) => out.write_str(signs.minus)?,
+            (true, false) => out.write_str(signs.plus)?,
+        }
+        // constants get written as is; otherwise, coefficients of 1 are left out
+        let coeff = if term.constant || term.abs != 1 {
+            Some(term.abs)
+        } else {
+            None
+        };
+        render(out, coeff, term.rest)?;
+        plus_flag = true;
+    }
+    Ok(())
+}
+
+/// A polynomial together with a `PolyFormatter`, as returned by `PolyFormatter::format()`.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a> {
+    formatter: &'a PolyFormatter,
+    poly: &'a Polynomial,
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.formatter
+            .write_terms(f, self.poly.coeffs.iter().copied().enumerate())
+    }
+}
+
+impl Polynomial {
+    /// Writes the polynomial out as LaTeX math (without surrounding `$`s), in the same
+    /// order and with the same handling of signs and unit coefficients as `Display`.
+    /// Exponents with more than one digit are wrapped in braces.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(poly![-5, 2, 0, -1].to_latex(), "-x^3 + 2x - 5");
+    /// assert_eq!(poly![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3].to_latex(), "3x^{12} + 1");
+    /// assert_eq!(Polynomial::zero().to_latex(), "0");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.to_latex_with_var("x")
+    }
+
+    /// Like `to_latex()`, but with the variable written as `var`, which is copied into the
+    /// output as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(poly![1, 0, -2].to_latex_with_var("\\theta"), "-2\\theta^2 + 1");
+    /// ```
+    pub fn to_latex_with_var(&self, var: &str) -> String {
+        if self.is_zero() {
+            return String::from("0");
+        }
+        let mut s = String::new();
+        write_sum(&mut s, SPACED, self.nonzero_terms(), |s, coeff, n| {
+            if let Some(abs) = coeff {
+                write!(s, "{}", abs)?;
+            }
+            match n {
+                0 => Ok(()),
+                1 => write!(s, "{}", var),
+                2..=9 => write!(s, "{}^{}", var, n),
+                _ => write!(s, "{}^{{{}}}", var, n),
+            }
+        })
+        .expect("writing to a String can't fail");
+        s
+    }
+
+    /// Writes the polynomial out as a presentation MathML `<math>` element, in the same
+    /// order and with the same handling of signs and unit coefficients as `Display`.
+    /// Coefficients are joined to powers of `x` with an invisible times (`&#x2062;`).
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(
+    ///     poly![-5, 0, 2].to_mathml(),
+    ///     "<math><mrow><mn>2</mn><mo>&#x2062;</mo><msup><mi>x</mi><mn>2</mn></msup>\
+    ///      <mo>-</mo><mn>5</mn></mrow></math>"
+    /// );
+    /// assert_eq!(
+    ///     poly![0, -1].to_mathml(),
+    ///     "<math><mrow><mo>-</mo><mi>x</mi></mrow></math>"
+    /// );
+    /// ```
+    pub fn to_mathml(&self) -> String {
+        self.to_mathml_with_var("x")
+    }
+
+    /// Like `to_mathml()`, but with the variable written as `var`, which is copied into the
+    /// `<mi>` elements as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// assert_eq!(
+    ///     poly![0, 1].to_mathml_with_var("t"),
+    ///     "<math><mrow><mi>t</mi></mrow></math>"
+    /// );
+    /// ```
+    pub fn to_mathml_with_var(&self, var: &str) -> String {
+        const MATHML: Signs = Signs {
+            leading_minus: "<mo>-</mo>",
+            plus: "<mo>+</mo>",
+            minus: "<mo>-</mo>",
+        };
+        let mut s = String::from("<math><mrow>");
+        if self.is_zero() {
+            s += "<mn>0</mn>";
+        }
+        write_sum(&mut s, MATHML, self.nonzero_terms(), |s, coeff, n| {
+            if let Some(abs) = coeff {
+                write!(s, "<mn>{}</mn>", abs)?;
+                if n > 0 {
+                    s.write_str("<mo>&#x2062;</mo>")?;
+                }
+            }
+            match n {
+                0 => Ok(()),
+                1 => write!(s, "<mi>{}</mi>", var),
+                _ => write!(s, "<msup><mi>{}</mi><mn>{}</mn></msup>", var, n),
+            }
+        })
+        .expect("writing to a String can't fail");
+        s += "</mrow></math>";
+        s
+    }
+
+    /// The nonzero terms from the highest power down, for `write_sum()`.
+    fn nonzero_terms(&self) -> impl Iterator<Item = Term<usize>> + '_ {
+        let terms = self.coeffs.iter().copied().enumerate().rev();
+        terms
+            .filter(|&(_, i)| i != 0)
+            .map(|(n, i)| Term::new(i, n == 0, n))
+    }
+}
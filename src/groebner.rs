@@ -0,0 +1,401 @@
+use crate::multi::{MonomialOrder, MultiPolynomial};
+use crate::Polynomial;
+
+/// The field the coefficients of a Gröbner basis computation are taken in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// The rationals. Polynomials stay integral throughout, with fractions cleared, so a
+    /// polynomial over the rationals is only determined up to a constant factor; results
+    /// are given primitive, with positive leading coefficients.
+    Rationals,
+    /// The integers modulo a prime `p`, with coefficients in the range `0..p`.
+    Prime(isize),
+}
+
+/// A reduced Gröbner basis of an ideal of multivariate polynomials, computed by Buchberger's
+/// algorithm. Each element is normalized (monic modulo a prime, primitive with positive
+/// leading coefficient over the rationals), so the basis is unique for the ideal, monomial
+/// order and field.
+///
+/// # Examples
+/// ```
+/// use polynomint::multi::{MonomialOrder, MultiPolynomial};
+/// use polynomint::groebner::{Field, GroebnerBasis};
+///
+/// let x = MultiPolynomial::var(2, 0);
+/// let y = MultiPolynomial::var(2, 1);
+/// let one = MultiPolynomial::constant(2, 1);
+/// // the unit circle meeting the line x = y
+/// let ideal = [&x * &x + &y * &y - one, &x - &y];
+///
+/// let basis = GroebnerBasis::new(&ideal, MonomialOrder::Lex, Field::Rationals);
+/// let basis: Vec<String> = basis.polys().iter().map(|g| g.to_string()).collect();
+/// assert_eq!(basis, ["x - y", "2y^2 - 1"]);
+///
+/// let basis = GroebnerBasis::new(&ideal, MonomialOrder::Lex, Field::Prime(5));
+/// let basis: Vec<String> = basis.polys().iter().map(|g| g.to_string()).collect();
+/// assert_eq!(basis, ["x + 4y", "y^2 + 2"]);
+///
+/// // a prime big enough that products of two residues don't fit in an isize
+/// let basis = GroebnerBasis::new(&ideal, MonomialOrder::Lex, Field::Prime(10000000019));
+/// let basis: Vec<String> = basis.polys().iter().map(|g| g.to_string()).collect();
+/// assert_eq!(basis, ["x + 10000000018y", "y^2 + 5000000009"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroebnerBasis {
+    nvars: usize,
+    order: MonomialOrder,
+    field: Field,
+    polys: Vec<MultiPolynomial>,
+}
+
+impl GroebnerBasis {
+    /// Computes the reduced Gröbner basis of the ideal generated by `generators`, which
+    /// all have to be in the same number of variables. Pairs are taken smallest least
+    /// common multiple first, and skipped when Buchberger's criteria show their
+    /// S-polynomials reduce to zero: when the two leading monomials are coprime, or when
+    /// a third leading monomial divides their least common multiple and both of its pairs
+    /// with them have already been dealt with.
+    ///
+    /// Panics if there are no generators, if they don't all have the same number of
+    /// variables, or if the field is `Field::Prime(p)` for `p` not prime. Over the
+    /// rationals, the coefficients along the way have to fit in an `isize`.
+    pub fn new(generators: &[MultiPolynomial], order: MonomialOrder, field: Field) -> Self {
+        let nvars = generators.first().expect("no generators").nvars;
+        assert!(
+            generators.iter().all(|g| g.nvars == nvars),
+            "generators have different numbers of variables"
+        );
+        if let Field::Prime(p) = field {
+            assert!(
+                p > 1 && Polynomial::is_prime(p as usize),
+                "modulus is not prime"
+            );
+        }
+        let mut basis: Vec<MultiPolynomial> = generators
+            .iter()
+            .map(|g| normalize(g.clone().with_order(order), field))
+            .filter(|g| !g.is_zero())
+            .collect();
+        let mut pairs: Vec<(usize, usize)> = (0..basis.len())
+            .flat_map(|j| (0..j).map(move |i| (i, j)))
+            .collect();
+        while !pairs.is_empty() {
+            let lcm_of = |&(i, j): &(usize, usize)| lcm(lead(&basis[i]), lead(&basis[j]));
+            // the normal selection strategy: the pair with the smallest lcm goes first
+            let next = (0..pairs.len())
+                .min_by(|&a, &b| order.cmp(&lcm_of(&pairs[a]), &lcm_of(&pairs[b])))
+                .unwrap();
+            let (i, j) = pairs.swap_remove(next);
+            let (lead_i, lead_j) = (lead(&basis[i]), lead(&basis[j]));
+            let l = lcm(lead_i, lead_j);
+            if lead_i.iter().zip(lead_j).all(|(&a, &b)| a == 0 || b == 0) {
+                continue;
+            }
+            let pending = |a: usize, b: usize| pairs.contains(&(a.min(b), a.max(b)));
+            let chain = (0..basis.len()).any(|k| {
+                k != i && k != j && divides(lead(&basis[k]), &l) && !pending(i, k) && !pending(j, k)
+            });
+            if chain {
+                continue;
+            }
+            let s = s_polynomial(&basis[i], &basis[j], field);
+            let r = normalize(reduce(&s, &basis, field), field);
+            if !r.is_zero() {
+                pairs.extend((0..basis.len()).map(|k| (k, basis.len())));
+                basis.push(r);
+            }
+        }
+
+        // drop elements whose leading monomials are multiples of others', then reduce each
+        // of the rest by the others so no term of any of them is divisible by a leading
+        // monomial
+        basis.sort_by(|f, g| order.cmp(lead(f), lead(g)));
+        let mut minimal: Vec<MultiPolynomial> = Vec::new();
+        for g in basis {
+            if !minimal.iter().any(|f| divides(lead(f), lead(&g))) {
+                minimal.push(g);
+            }
+        }
+        let mut polys = minimal.clone();
+        for (i, poly) in polys.iter_mut().enumerate() {
+            let others: Vec<MultiPolynomial> = minimal
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k != i)
+                .map(|(_, f)| f.clone())
+                .collect();
+            *poly = reduce(&minimal[i], &others, field);
+        }
+        polys.reverse();
+        Self {
+            nvars,
+            order,
+            field,
+            polys,
+        }
+    }
+
+    /// Returns the polynomials in the basis, from the largest leading monomial down.
+    pub fn polys(&self) -> &[MultiPolynomial] {
+        &self.polys
+    }
+
+    /// Returns the monomial order the basis was computed for.
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    /// Returns the field the basis was computed over.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Gives the normal form of `poly` with respect to the ideal: its remainder on division
+    /// by the basis, which is zero exactly when `poly` is in the ideal and otherwise only
+    /// depends on `poly` modulo the ideal. Over the rationals this is only up to a constant
+    /// factor, and is given primitive with positive leading coefficient. Panics if `poly`
+    /// doesn't have the basis's number of variables.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::{MonomialOrder, MultiPolynomial};
+    /// use polynomint::groebner::{Field, GroebnerBasis};
+    ///
+    /// let x = MultiPolynomial::var(2, 0);
+    /// let y = MultiPolynomial::var(2, 1);
+    /// let one = MultiPolynomial::constant(2, 1);
+    /// let ideal = [&x * &x + &y * &y - one, &x - &y];
+    /// let cube = &x * &x * x.clone();
+    ///
+    /// // x^3 = y^3 = y/2, and 1/2 = 3 mod 5
+    /// let basis = GroebnerBasis::new(&ideal, MonomialOrder::Lex, Field::Rationals);
+    /// assert_eq!(basis.normal_form(&cube).to_string(), "y");
+    /// let basis = GroebnerBasis::new(&ideal, MonomialOrder::Lex, Field::Prime(5));
+    /// assert_eq!(basis.normal_form(&cube).to_string(), "3y");
+    /// ```
+    pub fn normal_form(&self, poly: &MultiPolynomial) -> MultiPolynomial {
+        assert_eq!(
+            poly.nvars, self.nvars,
+            "polynomial has the wrong number of variables"
+        );
+        let poly = match self.field {
+            Field::Rationals => poly.clone(),
+            Field::Prime(p) => reduce_mod(poly.clone(), p),
+        };
+        reduce(&poly.with_order(self.order), &self.polys, self.field)
+    }
+
+    /// Checks whether `poly` is in the ideal, i.e. has normal form zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::{MonomialOrder, MultiPolynomial};
+    /// use polynomint::groebner::{Field, GroebnerBasis};
+    ///
+    /// let x = MultiPolynomial::var(2, 0);
+    /// let y = MultiPolynomial::var(2, 1);
+    /// let one = MultiPolynomial::constant(2, 1);
+    /// let ideal = [&x * &x + &y * &y - one, &x - &y];
+    /// let basis = GroebnerBasis::new(&ideal, MonomialOrder::GrevLex, Field::Rationals);
+    ///
+    /// assert!(basis.contains(&(&x * &x - &y * &y)));
+    /// assert!(basis.contains(&(&x * &y * 2 - MultiPolynomial::constant(2, 1))));
+    /// assert!(!basis.contains(&(&x + &y)));
+    /// ```
+    pub fn contains(&self, poly: &MultiPolynomial) -> bool {
+        self.normal_form(poly).is_zero()
+    }
+
+    /// Gives a Gröbner basis of the `k`th elimination ideal, the polynomials in the ideal
+    /// that only involve `x_k`, `x_(k+1)`, ...; these are the elements of a lexicographic
+    /// basis without `x_0` to `x_(k-1)`, and one gets computed first if this basis isn't
+    /// lexicographic. In particular, for a system with finitely many solutions,
+    /// eliminating all but the last variable gives a univariate polynomial whose roots
+    /// are the possible values of that variable.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    /// use polynomint::multi::{MonomialOrder, MultiPolynomial};
+    /// use polynomint::groebner::{Field, GroebnerBasis};
+    ///
+    /// let x = MultiPolynomial::var(2, 0);
+    /// let y = MultiPolynomial::var(2, 1);
+    /// let c = |n| MultiPolynomial::constant(2, n);
+    /// // xy = 2 and x + y = 3
+    /// let ideal = [&x * &y - c(2), &x + &y - c(3)];
+    /// let basis = GroebnerBasis::new(&ideal, MonomialOrder::GrevLex, Field::Rationals);
+    ///
+    /// let eliminated = basis.eliminate(1);
+    /// assert_eq!(eliminated.len(), 1);
+    /// let in_y = eliminated[0].to_univariate(1).unwrap();
+    /// assert_eq!(in_y, poly![2, -3, 1]);
+    /// assert_eq!(in_y.integer_roots(), vec![1, 2]);
+    /// ```
+    pub fn eliminate(&self, k: usize) -> Vec<MultiPolynomial> {
+        let lex;
+        let basis = if self.order == MonomialOrder::Lex {
+            self
+        } else {
+            lex = Self::new(&self.polys, MonomialOrder::Lex, self.field);
+            &lex
+        };
+        basis
+            .polys
+            .iter()
+            .filter(|g| g.terms.iter().all(|(m, _)| m[..k].iter().all(|&e| e == 0)))
+            .cloned()
+            .collect()
+    }
+}
+
+fn lead(poly: &MultiPolynomial) -> &[usize] {
+    &poly.terms[0].0
+}
+
+fn lcm(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().zip(b).map(|(&i, &j)| i.max(j)).collect()
+}
+
+fn divides(a: &[usize], b: &[usize]) -> bool {
+    a.iter().zip(b).all(|(i, j)| i <= j)
+}
+
+/// Multiplies `poly` by the monomial `m` and the constant `c`. Monomial orders respect
+/// multiplication, so the terms stay in order.
+fn mul_term(poly: &MultiPolynomial, m: &[usize], c: isize) -> MultiPolynomial {
+    let terms = poly.terms.iter().map(|(n, d)| {
+        let product = n.iter().zip(m).map(|(i, j)| i + j).collect();
+        (product, c * d)
+    });
+    MultiPolynomial {
+        nvars: poly.nvars,
+        order: poly.order,
+        terms: terms.filter(|&(_, c)| c != 0).collect(),
+    }
+}
+
+/// Like `mul_term()`, but for coefficients modulo `p`, taking the products in `i128` so
+/// that they can't overflow however big `p` is.
+fn mul_term_mod(poly: &MultiPolynomial, m: &[usize], c: isize, p: isize) -> MultiPolynomial {
+    let terms = poly.terms.iter().map(|(n, d)| {
+        let product = n.iter().zip(m).map(|(i, j)| i + j).collect();
+        (
+            product,
+            (c as i128 * *d as i128).rem_euclid(p as i128) as isize,
+        )
+    });
+    MultiPolynomial {
+        nvars: poly.nvars,
+        order: poly.order,
+        terms: terms.filter(|&(_, c)| c != 0).collect(),
+    }
+}
+
+fn reduce_mod(poly: MultiPolynomial, p: isize) -> MultiPolynomial {
+    let terms = poly.terms.into_iter().map(|(m, c)| (m, c.rem_euclid(p)));
+    MultiPolynomial::from_terms(poly.nvars, poly.order, terms.collect())
+}
+
+/// Scales a polynomial to the basis's canonical form: monic modulo a prime, or primitive
+/// with positive leading coefficient over the rationals.
+fn normalize(poly: MultiPolynomial, field: Field) -> MultiPolynomial {
+    let lc = match poly.terms.first() {
+        Some(&(_, c)) => c,
+        None => return poly,
+    };
+    match field {
+        Field::Rationals => {
+            let content = poly
+                .terms
+                .iter()
+                .fold(0, |acc, &(_, c)| Polynomial::int_gcd(acc, c));
+            let content = if lc < 0 { -content } else { content };
+            let terms = poly.terms.iter().map(|(m, c)| (m.clone(), c / content));
+            MultiPolynomial::from_terms(poly.nvars, poly.order, terms.collect())
+        }
+        Field::Prime(p) => {
+            let poly = reduce_mod(poly, p);
+            match poly.terms.first() {
+                Some(&(_, lc)) => {
+                    let inv = Polynomial::inv_mod_p(lc, p);
+                    mul_term_mod(&poly, &vec![0; poly.nvars], inv, p)
+                }
+                None => poly,
+            }
+        }
+    }
+}
+
+/// Gives `a * f - b * m * g`, the basic step of both S-polynomials and reduction, where
+/// `m * g` has the same leading monomial as `f` and `a` and `b` are chosen to cancel it.
+fn cancel_leading(
+    f: &MultiPolynomial,
+    g: &MultiPolynomial,
+    m: &[usize],
+    field: Field,
+) -> (MultiPolynomial, isize) {
+    let (lc_f, lc_g) = (f.terms[0].1, g.terms[0].1);
+    let zero = vec![0; f.nvars];
+    match field {
+        Field::Rationals => {
+            let d = Polynomial::int_gcd(lc_f, lc_g);
+            let (a, b) = (lc_g / d, lc_f / d);
+            (&mul_term(f, &zero, a) - &mul_term(g, m, b), a)
+        }
+        Field::Prime(p) => {
+            let b = (lc_f as i128 * Polynomial::inv_mod_p(lc_g, p) as i128 % p as i128) as isize;
+            // both sides are reduced, so their difference can't overflow
+            (reduce_mod(f - &mul_term_mod(g, m, b, p), p), 1)
+        }
+    }
+}
+
+fn s_polynomial(f: &MultiPolynomial, g: &MultiPolynomial, field: Field) -> MultiPolynomial {
+    let l = lcm(lead(f), lead(g));
+    let shift = |poly: &MultiPolynomial| -> Vec<usize> {
+        l.iter().zip(lead(poly)).map(|(a, b)| a - b).collect()
+    };
+    let f = mul_term(f, &shift(f), 1);
+    let (s, _) = cancel_leading(&f, g, &shift(g), field);
+    s
+}
+
+/// Divides `poly` by `divisors` until no term of the remainder is divisible by any of their
+/// leading monomials, and gives the remainder, normalized.
+fn reduce(poly: &MultiPolynomial, divisors: &[MultiPolynomial], field: Field) -> MultiPolynomial {
+    let mut poly = poly.clone();
+    // the terms that no leading monomial divides, which are set aside as they're found
+    let mut rem: Vec<(Vec<usize>, isize)> = Vec::new();
+    while let Some((m, c)) = poly.terms.first().cloned() {
+        let divisor = divisors.iter().find(|g| divides(lead(g), &m));
+        match divisor {
+            Some(g) => {
+                let shift: Vec<usize> = m.iter().zip(lead(g)).map(|(a, b)| a - b).collect();
+                let (reduced, scale) = cancel_leading(&poly, g, &shift, field);
+                poly = reduced;
+                // clearing denominators scaled everything, including what's set aside
+                if scale != 1 {
+                    for term in rem.iter_mut() {
+                        term.1 *= scale;
+                    }
+                }
+            }
+            None => {
+                poly.terms.remove(0);
+                rem.push((m, c));
+            }
+        }
+    }
+    let rem = MultiPolynomial {
+        nvars: poly.nvars,
+        order: poly.order,
+        terms: rem,
+    };
+    match field {
+        Field::Rationals => normalize(rem, field),
+        // modulo a prime the remainder is exact, so leave it as it is
+        Field::Prime(_) => rem,
+    }
+}
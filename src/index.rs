@@ -1,5 +1,6 @@
-use std::ops::{Index, IndexMut};
+use std::ops::Index;
 
+use crate::error::PolyError;
 use crate::Polynomial;
 
 impl Index<usize> for Polynomial {
@@ -9,8 +10,25 @@ impl Index<usize> for Polynomial {
     }
 }
 
-impl IndexMut<usize> for Polynomial {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut (self.coeffs[index])
+impl Polynomial {
+    /// Gives the coefficient of `x^index` like indexing does, but with an error rather than
+    /// a panic when `index` is past the degree.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![3, 0, -1];
+    /// assert_eq!(poly.try_index(2), Ok(-1));
+    /// assert_eq!(poly.try_index(3), Err(PolyError::IndexOutOfRange { index: 3, len: 3 }));
+    /// ```
+    pub fn try_index(&self, index: usize) -> Result<isize, PolyError> {
+        self.coeffs
+            .get(index)
+            .copied()
+            .ok_or(PolyError::IndexOutOfRange {
+                index,
+                len: self.coeffs.len(),
+            })
     }
 }
@@ -0,0 +1,197 @@
+use crate::rational::Rational;
+use crate::Polynomial;
+
+/// The ways interpolating a polynomial through a list of points can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolationError {
+    /// Two of the points have the same x-coordinate (modulo `p`, for `interpolate_mod()`).
+    DuplicateX(isize),
+    /// The modulus passed to `interpolate_mod()` isn't prime.
+    NonPrimeModulus(isize),
+    /// The interpolating polynomial exists, but doesn't have integer coefficients; it's
+    /// equal to `numerator / denominator`, where `denominator` is positive and as small
+    /// as possible.
+    NonIntegral {
+        numerator: Polynomial,
+        denominator: isize,
+    },
+    /// A coefficient of the interpolating polynomial, or a fraction computed along the
+    /// way, doesn't fit in an `isize`.
+    Overflow,
+}
+
+impl std::fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateX(x) => write!(f, "x-coordinate {} appears more than once", x),
+            Self::NonPrimeModulus(p) => write!(f, "modulus {} is not prime", p),
+            Self::NonIntegral {
+                numerator,
+                denominator,
+            } => write!(
+                f,
+                "interpolating polynomial ({}) / {} has non-integer coefficients",
+                numerator, denominator
+            ),
+            Self::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for InterpolationError {}
+
+impl Polynomial {
+    /// Gives the unique polynomial of degree less than `points.len()` which passes through
+    /// every point `(x, y)` in `points`, using Newton's divided differences in exact
+    /// fractions. If the x-coordinates aren't all distinct, if the polynomial has
+    /// non-integer coefficients, or if a number along the way doesn't fit in an `isize`,
+    /// returns the appropriate `InterpolationError` instead; with non-integer
+    /// coefficients, the error still holds the exact answer as a polynomial over a
+    /// denominator.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, interpolate::InterpolationError};
+    ///
+    /// let squares = [(0, 0), (1, 1), (2, 4), (3, 9)];
+    /// assert_eq!(Polynomial::interpolate(&squares), Ok(poly![0, 0, 1]));
+    ///
+    /// let cubic = poly![3, -1, 0, 2];
+    /// let points: Vec<(isize, isize)> = (-2..2).map(|x| (x, cubic.eval(x))).collect();
+    /// assert_eq!(Polynomial::interpolate(&points), Ok(cubic.clone()));
+    ///
+    /// // 0, 1, 3, 6 are the triangular numbers x(x + 1)/2
+    /// let triangular = [(0, 0), (1, 1), (2, 3), (3, 6)];
+    /// assert_eq!(
+    ///     Polynomial::interpolate(&triangular),
+    ///     Err(InterpolationError::NonIntegral { numerator: poly![0, 1, 1], denominator: 2 })
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Polynomial::interpolate(&[(1, 2), (1, 3)]),
+    ///     Err(InterpolationError::DuplicateX(1))
+    /// );
+    ///
+    /// // plenty of points is fine, as long as the answer fits
+    /// let points: Vec<(isize, isize)> = (0..40).map(|x| (x, cubic.eval(x))).collect();
+    /// assert_eq!(Polynomial::interpolate(&points), Ok(cubic));
+    /// let points = [(0, 0), (1, isize::MAX), (2, 0)];
+    /// assert_eq!(Polynomial::interpolate(&points), Err(InterpolationError::Overflow));
+    /// ```
+    pub fn interpolate(points: &[(isize, isize)]) -> Result<Self, InterpolationError> {
+        for (i, &(x, _)) in points.iter().enumerate() {
+            if points[..i].iter().any(|&(other, _)| other == x) {
+                return Err(InterpolationError::DuplicateX(x));
+            }
+        }
+        Self::newton(points).ok_or(InterpolationError::Overflow)?
+    }
+
+    /// Does the work of `interpolate()` for distinct x-coordinates, giving `None` on
+    /// overflow.
+    fn newton(points: &[(isize, isize)]) -> Option<Result<Self, InterpolationError>> {
+        // build the divided differences in place: after round j, diffs[i] for i >= j is
+        // f[x_(i - j), ..., x_i], so in the end diffs[i] is f[x_0, ..., x_i]
+        let mut diffs: Vec<Rational> = points.iter().map(|&(_, y)| Rational::from(y)).collect();
+        for j in 1..points.len() {
+            for i in (j..points.len()).rev() {
+                let dx = points[i].0.checked_sub(points[i - j].0)?;
+                diffs[i] = diffs[i]
+                    .checked_sub(diffs[i - 1])?
+                    .checked_div(Rational::from(dx))?;
+            }
+        }
+        // then expand the Newton form f[x_0] + (x - x_0)(f[x_0, x_1] + (x - x_1)(...))
+        // from the inside out; every partial result is the interpolant of some of the
+        // points, so nothing gets much bigger than the answer
+        let mut coeffs: Vec<Rational> = Vec::new();
+        for (&(x, _), &diff) in points.iter().zip(diffs.iter()).rev() {
+            // multiply by (x - x_i), then add the divided difference
+            let mut next = vec![Rational::zero(); coeffs.len() + 1];
+            for (n, &c) in coeffs.iter().enumerate() {
+                next[n + 1] = next[n + 1].checked_add(c)?;
+                next[n] = next[n].checked_sub(c.checked_mul(Rational::from(x))?)?;
+            }
+            next[0] = next[0].checked_add(diff)?;
+            while next.last().is_some_and(Rational::is_zero) {
+                next.pop();
+            }
+            coeffs = next;
+        }
+        // bring everything over the least common denominator; since each coefficient is
+        // in lowest terms, the numerators have no factor in common with it
+        let denominator = coeffs.iter().try_fold(1isize, |acc, c| {
+            (acc / Self::int_gcd(acc, c.denom())).checked_mul(c.denom())
+        })?;
+        let numerator = coeffs
+            .iter()
+            .map(|c| c.numer().checked_mul(denominator / c.denom()))
+            .collect::<Option<Vec<_>>>()?;
+        Some(if denominator == 1 {
+            Ok(Self::new(numerator))
+        } else {
+            Err(InterpolationError::NonIntegral {
+                numerator: Self::new(numerator),
+                denominator,
+            })
+        })
+    }
+
+    /// Gives the unique polynomial of degree less than `points.len()` which passes through
+    /// every point `(x, y)` in `points` modulo the prime `p`, with coefficients in the range
+    /// `0..p`. Returns an `InterpolationError` if `p` isn't prime or if two of the
+    /// x-coordinates are the same modulo `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, interpolate::InterpolationError};
+    ///
+    /// // x(x + 1)/2 = 4x^2 + 4x mod 7, since 2 * 4 = 1 mod 7
+    /// let triangular = [(0, 0), (1, 1), (2, 3), (3, 6)];
+    /// assert_eq!(Polynomial::interpolate_mod(&triangular, 7), Ok(poly![0, 4, 4]));
+    ///
+    /// assert_eq!(
+    ///     Polynomial::interpolate_mod(&[(1, 0), (8, 1)], 7),
+    ///     Err(InterpolationError::DuplicateX(8))
+    /// );
+    /// assert_eq!(
+    ///     Polynomial::interpolate_mod(&triangular, 6),
+    ///     Err(InterpolationError::NonPrimeModulus(6))
+    /// );
+    /// ```
+    pub fn interpolate_mod(
+        points: &[(isize, isize)],
+        p: isize,
+    ) -> Result<Self, InterpolationError> {
+        if p < 2 || !Self::is_prime(p as usize) {
+            return Err(InterpolationError::NonPrimeModulus(p));
+        }
+        let xs: Vec<isize> = points.iter().map(|&(x, _)| x.rem_euclid(p)).collect();
+        for (i, &x) in xs.iter().enumerate() {
+            if xs[..i].contains(&x) {
+                return Err(InterpolationError::DuplicateX(points[i].0));
+            }
+        }
+        let master = xs.iter().fold(Self::constant(1), |acc, &x| {
+            acc.mul_mod(&Self::new(vec![(-x).rem_euclid(p), 1]), p)
+        });
+        // accumulate in i128, since two residues can add up to more than an isize holds
+        let mut output = vec![0i128; master.coeffs.len() - 1];
+        for (&x, &(_, y)) in xs.iter().zip(points.iter()) {
+            // divide x - x_i out of master by synthetic division from the top down
+            let mut basis = vec![0; master.coeffs.len() - 1];
+            let mut acc = 0;
+            for n in (1..master.coeffs.len()).rev() {
+                acc = (acc * x as i128 + master.coeffs[n] as i128) % p as i128;
+                basis[n - 1] = acc as isize;
+            }
+            let basis = Self::new(basis);
+            let weight = basis.eval_mod(x, p);
+            let scale = y.rem_euclid(p) as i128 * Self::inv_mod_p(weight, p) as i128 % p as i128;
+            for (out, &b) in output.iter_mut().zip(basis.coeffs.iter()) {
+                *out = (*out + scale * b as i128) % p as i128;
+            }
+        }
+        Ok(Self::new(output.into_iter().map(|c| c as isize).collect()))
+    }
+}
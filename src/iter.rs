@@ -9,6 +9,12 @@ impl IntoIterator for Polynomial {
     }
 }
 
+impl std::iter::FromIterator<isize> for Polynomial {
+    fn from_iter<I: IntoIterator<Item = isize>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
 impl Polynomial {
     /// Returns an immutably referencing iterator over the underlying
     /// `Vec` of coefficients.
@@ -29,21 +35,4 @@ impl Polynomial {
     pub fn iter(&'_ self) -> std::slice::Iter<'_, isize> {
         self.coeffs.iter()
     }
-
-    /// Returns a mutably referencing iterator over the underlying
-    /// `Vec` of coefficients.
-    ///
-    /// # Examples
-    /// ```
-    /// use polynomint::{Polynomial, poly};
-    ///
-    /// let mut poly = poly![5, 3, -2, 1];
-    /// for coeff in poly.iter_mut() {
-    ///     *coeff *= 3;
-    /// }
-    /// assert_eq!(poly, poly![15, 9, -6, 3]);
-    /// ```
-    pub fn iter_mut(&'_ mut self) -> std::slice::IterMut<'_, isize> {
-        self.coeffs.iter_mut()
-    }
 }
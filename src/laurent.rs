@@ -0,0 +1,283 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::format::{write_sum, Term, SPACED};
+use crate::Polynomial;
+
+/// A Laurent polynomial, which can have negative powers of `x` as well as positive ones,
+/// like `x^4 + 2 + x^-3`. It's stored as a `Polynomial` with nonzero constant term times
+/// `x` to the power of its valuation, the lowest power that appears.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, laurent::LaurentPolynomial};
+///
+/// let f = LaurentPolynomial::new(poly![1, 0, 0, 2, 0, 0, 0, 1], -3); // x^-3 + 2 + x^4
+/// assert_eq!(f.to_string(), "x^4 + 2 + x^-3");
+/// assert_eq!(f.valuation(), Some(-3));
+/// assert_eq!(f.degree(), Some(4));
+/// assert_eq!(f.coeff(0), 2);
+///
+/// let t = LaurentPolynomial::monomial(1, 1);
+/// let t_inv = LaurentPolynomial::monomial(1, -1);
+/// assert_eq!(&t * &t_inv, LaurentPolynomial::from(poly![1]));
+/// assert_eq!((&t + &t_inv).pow(2).to_string(), "x^2 + 2 + x^-2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaurentPolynomial {
+    poly: Polynomial,
+    valuation: isize,
+}
+
+impl LaurentPolynomial {
+    /// Creates the Laurent polynomial `poly * x^shift`.
+    pub fn new(poly: Polynomial, shift: isize) -> Self {
+        let zeros = match poly.coeffs.iter().position(|&c| c != 0) {
+            Some(zeros) => zeros,
+            None => return Self::zero(),
+        };
+        Self {
+            poly: Polynomial {
+                coeffs: poly.coeffs[zeros..].to_vec(),
+            },
+            valuation: shift + zeros as isize,
+        }
+    }
+
+    /// Creates the zero Laurent polynomial.
+    pub fn zero() -> Self {
+        Self {
+            poly: Polynomial::zero(),
+            valuation: 0,
+        }
+    }
+
+    /// Creates the monomial `c * x^n`.
+    pub fn monomial(c: isize, n: isize) -> Self {
+        Self::new(Polynomial::constant(c), n)
+    }
+
+    /// Checks whether this is the zero Laurent polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    /// Gives the lowest power with a nonzero coefficient, or `None` for zero.
+    pub fn valuation(&self) -> Option<isize> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.valuation)
+        }
+    }
+
+    /// Gives the highest power with a nonzero coefficient, or `None` for zero.
+    pub fn degree(&self) -> Option<isize> {
+        self.valuation().map(|v| v + self.poly.degree())
+    }
+
+    /// Gives the coefficient of `x^n`, which is 0 for any power that doesn't appear.
+    pub fn coeff(&self, n: isize) -> isize {
+        if n < self.valuation {
+            0
+        } else {
+            self.poly.coeff((n - self.valuation) as usize)
+        }
+    }
+
+    /// Splits the Laurent polynomial into a polynomial with nonzero constant term (or zero)
+    /// and the power of `x` it's multiplied by.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, laurent::LaurentPolynomial};
+    ///
+    /// let f = LaurentPolynomial::new(poly![0, 0, 3, 1], -5); // 3x^-3 + x^-2
+    /// assert_eq!(f.to_parts(), (poly![3, 1], -3));
+    /// ```
+    pub fn to_parts(&self) -> (Polynomial, isize) {
+        (self.poly.clone(), self.valuation)
+    }
+
+    /// Gives the Laurent polynomial as an ordinary polynomial, if it has no negative powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, laurent::LaurentPolynomial};
+    ///
+    /// assert_eq!(LaurentPolynomial::new(poly![1, 1], 2).to_polynomial(), Some(poly![0, 0, 1, 1]));
+    /// assert_eq!(LaurentPolynomial::new(poly![1, 1], -1).to_polynomial(), None);
+    /// ```
+    pub fn to_polynomial(&self) -> Option<Polynomial> {
+        if self.is_zero() {
+            return Some(Polynomial::zero());
+        }
+        if self.valuation < 0 {
+            return None;
+        }
+        let mut coeffs = vec![0; self.valuation as usize];
+        coeffs.extend(self.poly.coeffs.iter());
+        Some(Polynomial { coeffs })
+    }
+
+    /// Substitutes `x^-1` for `x`, which mirrors the powers around zero; for knot
+    /// polynomials, this gives the invariant of the mirror image.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, laurent::LaurentPolynomial};
+    ///
+    /// let f = LaurentPolynomial::new(poly![1, 0, 2, 3], -1); // x^-1 + 2x + 3x^2
+    /// assert_eq!(f.reflect().to_string(), "x + 2x^-1 + 3x^-2");
+    /// ```
+    pub fn reflect(&self) -> Self {
+        let coeffs = self.poly.coeffs.iter().rev().copied().collect();
+        match self.degree() {
+            Some(degree) => Self::new(Polynomial::new(coeffs), -degree),
+            None => Self::zero(),
+        }
+    }
+
+    /// Gives the inverse, if there is one in the Laurent polynomials, which is only for
+    /// the units `±x^n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::laurent::LaurentPolynomial;
+    ///
+    /// let f = LaurentPolynomial::monomial(-1, 3);
+    /// assert_eq!(f.inverse(), Some(LaurentPolynomial::monomial(-1, -3)));
+    /// assert_eq!(LaurentPolynomial::monomial(2, 3).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        match self.poly.coeffs.as_slice() {
+            [c] if c.abs() == 1 => Some(Self::monomial(*c, -self.valuation)),
+            _ => None,
+        }
+    }
+
+    /// Gives the polynomial `p` with `self = p * x^low`, for `low` at most the valuation.
+    fn aligned_to(&self, low: isize) -> Polynomial {
+        let mut coeffs = vec![0; (self.valuation - low) as usize];
+        coeffs.extend(self.poly.coeffs.iter());
+        Polynomial { coeffs }
+    }
+
+    /// Raises the Laurent polynomial to the power `e` by repeated squaring.
+    pub fn pow(&self, e: u32) -> Self {
+        Self {
+            poly: self.poly.pow(e),
+            valuation: self.valuation * e as isize,
+        }
+    }
+}
+
+impl From<Polynomial> for LaurentPolynomial {
+    fn from(poly: Polynomial) -> Self {
+        Self::new(poly, 0)
+    }
+}
+
+impl From<&Polynomial> for LaurentPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        Self::new(poly.clone(), 0)
+    }
+}
+
+impl std::fmt::Display for LaurentPolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let terms = self.poly.coeffs.iter().enumerate().rev();
+        let terms = terms
+            .filter(|&(_, &c)| c != 0)
+            .map(|(i, &c)| (self.valuation + i as isize, c))
+            .map(|(n, c)| Term::new(c, n == 0, n));
+        write_sum(f, SPACED, terms, |f, coeff, n| {
+            if let Some(abs) = coeff {
+                write!(f, "{}", abs)?;
+            }
+            if n != 0 {
+                write!(f, "x")?;
+            }
+            if n != 0 && n != 1 {
+                write!(f, "^{}", n)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Add for &LaurentPolynomial {
+    type Output = LaurentPolynomial;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.is_zero() {
+            return rhs.clone();
+        } else if rhs.is_zero() {
+            return self.clone();
+        }
+        // line both up on the lower valuation
+        let low = self.valuation.min(rhs.valuation);
+        LaurentPolynomial::new(self.aligned_to(low) + rhs.aligned_to(low), low)
+    }
+}
+
+impl Add for LaurentPolynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for LaurentPolynomial {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            poly: -self.poly,
+            valuation: self.valuation,
+        }
+    }
+}
+
+impl Sub for &LaurentPolynomial {
+    type Output = LaurentPolynomial;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs.clone())
+    }
+}
+
+impl Sub for LaurentPolynomial {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for &LaurentPolynomial {
+    type Output = LaurentPolynomial;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // the product of two polynomials with nonzero constant terms has one too
+        LaurentPolynomial::new(&self.poly * &rhs.poly, self.valuation + rhs.valuation)
+    }
+}
+
+impl Mul for LaurentPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<isize> for &LaurentPolynomial {
+    type Output = LaurentPolynomial;
+    fn mul(self, rhs: isize) -> Self::Output {
+        LaurentPolynomial::new(&self.poly * rhs, self.valuation)
+    }
+}
+
+impl Mul<isize> for LaurentPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self::Output {
+        &self * rhs
+    }
+}
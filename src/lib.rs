@@ -1,9 +1,33 @@
 pub mod add;
+pub mod cas;
+pub mod crt;
+pub mod div;
+pub mod encode;
+pub mod error;
+pub mod eval;
+pub mod expr;
+pub mod factor;
+pub mod format;
+pub mod groebner;
 pub mod index;
+pub mod interpolate;
 pub mod iter;
+pub mod laurent;
 pub mod math;
 pub mod mul;
+pub mod multi;
+pub mod multipoint;
+pub mod parse;
+pub mod partial;
+pub mod pow;
+pub mod qpoly;
+pub mod rational;
+pub mod rational_function;
 pub mod rem;
+#[cfg(feature = "serde")]
+pub mod serialize;
+pub mod series;
+pub mod sparse;
 pub mod sub;
 
 /// A wrapper struct around a `Vec<isize>` which treats the entries of the `Vec` as the coefficients
@@ -111,10 +135,66 @@ impl Polynomial {
         &(self.coeffs)
     }
 
-    /// Returns a mutable reference to `self`'s vector of coefficients, in order of
-    /// ascending degree (`poly.coeffs_mut()[n]` is the `x^n` coefficient of `poly`).
-    pub fn coeffs_mut(&mut self) -> &mut Vec<isize> {
-        &mut (self.coeffs)
+    /// Returns a guard that gives mutable access to `self`'s vector of coefficients, in
+    /// order of ascending degree, and removes any trailing zeroes left behind once it's
+    /// dropped. Use `coeffs_mut().iter_mut()` to change every coefficient in turn.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let mut poly = poly![5, 3, -2, 1];
+    /// for coeff in poly.coeffs_mut().iter_mut() {
+    ///     *coeff *= 3;
+    /// }
+    /// assert_eq!(poly, poly![15, 9, -6, 3]);
+    ///
+    /// poly.coeffs_mut()[3] = 0;
+    /// assert_eq!(poly.degree(), 2);
+    /// poly.coeffs_mut().push(7);
+    /// assert_eq!(poly, poly![15, 9, -6, 7]);
+    /// ```
+    pub fn coeffs_mut(&mut self) -> CoeffsMut<'_> {
+        CoeffsMut { poly: self }
+    }
+
+    /// Gives the coefficient of `x^n`, which is 0 for any `n` past the degree.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![3, 0, -1];
+    /// assert_eq!(poly.coeff(0), 3);
+    /// assert_eq!(poly.coeff(2), -1);
+    /// assert_eq!(poly.coeff(10), 0);
+    /// ```
+    pub fn coeff(&self, n: usize) -> isize {
+        self.coeffs.get(n).copied().unwrap_or(0)
+    }
+
+    /// Sets the coefficient of `x^n` to `c`, growing the vector of coefficients if `n` is
+    /// past the degree, and shrinking it if this zeroes out the leading coefficient.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let mut poly = poly![3, 0, -1];
+    /// poly.set_coeff(4, 2);
+    /// assert_eq!(poly, poly![3, 0, -1, 0, 2]);
+    /// poly.set_coeff(4, 0);
+    /// assert_eq!(poly, poly![3, 0, -1]);
+    /// ```
+    pub fn set_coeff(&mut self, n: usize, c: isize) {
+        if n >= self.coeffs.len() {
+            if c == 0 {
+                return;
+            }
+            self.coeffs.resize(n + 1, 0);
+        }
+        self.coeffs[n] = c;
+        self.reduce();
     }
 
     /// Removes trailing zeroes from a polynomial. Used to make sure the API only exposes
@@ -128,62 +208,36 @@ impl Polynomial {
     }
 }
 
+/// Mutable access to a polynomial's coefficients, given out by `Polynomial::coeffs_mut()`.
+/// Dereferences to the underlying `Vec<isize>`, and removes any trailing zeroes from it
+/// when dropped, so the polynomial never ends up with a zero leading coefficient.
+pub struct CoeffsMut<'a> {
+    poly: &'a mut Polynomial,
+}
+
+impl std::ops::Deref for CoeffsMut<'_> {
+    type Target = Vec<isize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.poly.coeffs
+    }
+}
+
+impl std::ops::DerefMut for CoeffsMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.poly.coeffs
+    }
+}
+
+impl Drop for CoeffsMut<'_> {
+    fn drop(&mut self) {
+        self.poly.reduce();
+    }
+}
+
 impl std::fmt::Display for Polynomial {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        // if our polynomial is zero, the big-ass else block doesn't do anything, so
-        // we just separately handle that case
-        if self.is_zero() {
-            s += &format!("{}", 0);
-        } else {
-            // this flag is basically "have we written a term yet", so we know whether
-            // to write plus/minus signs and to treat minus signs as operations rather
-            // than as prefixes
-            let mut plus_flag = false;
-            for (n, &i) in self.coeffs.iter().enumerate().rev() {
-                // only display terms with nonzero coefficients
-                if i != 0 {
-                    // if we're past the first term, print the appropriate operation
-                    // sign first
-                    if plus_flag {
-                        if i < 0 {
-                            s += " - ";
-                        } else {
-                            s += " + "
-                        }
-                    }
-                    // if our term is constant,
-                    if n == 0 {
-                        // just display that constant, or its absolute value if we already
-                        // wrote a minus sign
-                        s += &format!("{}", if plus_flag { i.abs() } else { i });
-                    // if our term is linear,
-                    } else if n == 1 {
-                        // if it's 1, just write "x";
-                        if i == 1 {
-                            s += "x";
-                        // if it's -1, and if we're writing the first term, put a minus sign
-                        // in front
-                        } else if i == -1 {
-                            s += &format!("{}x", if plus_flag { "" } else { "-" });
-                        // otherwise, just display the coefficient, or its absolute value
-                        // if we already wrote a minus sign
-                        } else {
-                            s += &format!("{}x", if plus_flag { i.abs() } else { i });
-                        }
-                    // rest of cases as above, but with the powers being displayed as well
-                    } else if i == 1 {
-                        s += &format!("x^{}", n);
-                    } else if i == -1 {
-                        s += &format!("{}x^{}", if plus_flag { "" } else { "-" }, n);
-                    } else {
-                        s += &format!("{}x^{}", if plus_flag { i.abs() } else { i }, n);
-                    }
-                    plus_flag = true;
-                }
-            }
-        }
-        write!(f, "{}", s)
+        write!(f, "{}", format::PolyFormatter::new().format(self))
     }
 }
 
@@ -191,16 +245,16 @@ impl std::fmt::Display for Polynomial {
 #[macro_export]
 macro_rules! poly {
     () => (
-        Polynomial::zero();
+        Polynomial::zero()
     );
     ($($x:expr),*) => (
-        Polynomial::new(vec![$($x),*]);
+        Polynomial::new(vec![$($x),*])
     )
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{poly, Polynomial};
+    use crate::Polynomial;
     #[test]
     fn it_works() {
         let mut quadratic = poly![1, 2, 1]; // x^2 + 2x + 1
@@ -227,5 +281,11 @@ mod tests {
         another *= &pair;
         assert_eq!(another, poly![-5, -11, -1, 13, 10, 2]);
         assert_eq!(another.derivative(), poly![-11, -2, 39, 40, 10]);
+        // subtracting from zero negates, whether by value or by reference
+        assert_eq!(Polynomial::zero() - poly![1, 2], poly![-1, -2]);
+        assert_eq!(&Polynomial::zero() - &poly![1, 2], poly![-1, -2]);
+        let mut zero = Polynomial::zero();
+        zero -= poly![1, 2];
+        assert_eq!(zero, poly![-1, -2]);
     }
 }
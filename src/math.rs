@@ -1,3 +1,8 @@
+use std::convert::TryFrom;
+
+use crate::error::PolyError;
+use crate::qpoly::QPolynomial;
+use crate::rational::Rational;
 use crate::Polynomial;
 
 impl Polynomial {
@@ -19,7 +24,7 @@ impl Polynomial {
     }
 
     /// Gives a new polynomial equal to the remainder of the old one when taken
-    /// modulo `n`.
+    /// modulo `n`. Panics if `n` is zero; `try_rem_euclid()` gives an error instead.
     ///
     /// # Examples
     /// ```
@@ -44,6 +49,28 @@ impl Polynomial {
         }
     }
 
+    /// Like `rem_euclid()`, but gives an error rather than panicking if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![6, -5, 3];
+    /// assert_eq!(poly.try_rem_euclid(4), Ok(poly![2, 3, 3]));
+    /// assert_eq!(poly.try_rem_euclid(0), Err(PolyError::ZeroModulus));
+    /// ```
+    pub fn try_rem_euclid(&self, n: isize) -> Result<Self, PolyError> {
+        if n == 0 {
+            return Err(PolyError::ZeroModulus);
+        }
+        // the only way checked_rem_euclid() can fail now is isize::MIN modulo -1, which is 0
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|c| c.checked_rem_euclid(n).unwrap_or(0));
+        Ok(Self::new(coeffs.collect()))
+    }
+
     /// Creates a new polynomial which is the derivative of the old one.
     ///
     /// # Examples
@@ -69,7 +96,147 @@ impl Polynomial {
         }
     }
 
-    /// Plugs in a specific `isize` value `x` to the polynomial.
+    /// Gives the `k`th derivative, so that `nth_derivative(1)` is `derivative()`. Panics
+    /// if a coefficient overflows; `try_nth_derivative()` gives an error instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, 1, 1, 1, 1]; // x^4 + x^3 + x^2 + x + 1
+    /// assert_eq!(poly.nth_derivative(2), poly![2, 6, 12]);
+    /// assert_eq!(poly.nth_derivative(2), poly.derivative().derivative());
+    /// assert_eq!(poly.nth_derivative(5), Polynomial::zero());
+    /// ```
+    pub fn nth_derivative(&self, k: usize) -> Self {
+        self.try_nth_derivative(k)
+            .expect("overflow in nth_derivative")
+    }
+
+    /// Like `nth_derivative()`, but gives an error rather than panicking on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let mut coeffs = vec![0; 31];
+    /// coeffs[30] = 1;
+    /// let poly = Polynomial::new(coeffs); // x^30
+    /// assert_eq!(poly.try_nth_derivative(2), Ok(poly.derivative().derivative()));
+    /// assert_eq!(poly.try_nth_derivative(30), Err(PolyError::Overflow)); // 30!
+    /// ```
+    pub fn try_nth_derivative(&self, k: usize) -> Result<Self, PolyError> {
+        // x^n becomes n(n - 1)...(n - k + 1) x^(n - k)
+        let falling = |n: usize| {
+            ((n - k + 1)..=n).try_fold(1isize, |acc, i| acc.checked_mul(isize::try_from(i).ok()?))
+        };
+        let terms = self.coeffs.iter().enumerate().skip(k);
+        let coeffs = terms.map(|(n, &c)| match c {
+            0 => Some(0),
+            c => falling(n)?.checked_mul(c),
+        });
+        coeffs
+            .collect::<Option<Vec<_>>>()
+            .map(Self::new)
+            .ok_or(PolyError::Overflow)
+    }
+
+    /// Gives the `k`th Hasse derivative, which takes `x^n` to `binomial(n, k) x^(n - k)`:
+    /// the `k`th derivative divided by `k!`, which always has integer coefficients. It's
+    /// the coefficient of `h^k` in `p(x + h)`. Panics if a coefficient overflows;
+    /// `try_hasse_derivative()` gives an error instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![0, 0, 0, 0, 1]; // x^4
+    /// assert_eq!(poly.hasse_derivative(2), poly![0, 0, 6]);
+    /// assert_eq!(poly.nth_derivative(2), poly.hasse_derivative(2) * 2);
+    /// ```
+    pub fn hasse_derivative(&self, k: usize) -> Self {
+        self.try_hasse_derivative(k)
+            .expect("overflow in hasse_derivative")
+    }
+
+    /// Like `hasse_derivative()`, but gives an error rather than panicking on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let mut coeffs = vec![0; 63];
+    /// coeffs[62] = 1;
+    /// let poly = Polynomial::new(coeffs); // x^62
+    /// let mut expected = vec![0; 32];
+    /// expected[31] = 465428353255261088; // binomial(62, 31)
+    /// assert_eq!(poly.try_hasse_derivative(31), Ok(Polynomial::new(expected)));
+    /// assert_eq!((&poly * 20).try_hasse_derivative(31), Err(PolyError::Overflow));
+    /// ```
+    pub fn try_hasse_derivative(&self, k: usize) -> Result<Self, PolyError> {
+        let binomial = |n: usize| {
+            // binomial(n, k) = binomial(n, n - k), and going up to the smaller of the two
+            // means every step is no bigger than the result
+            let k = k.min(n - k) as u128;
+            (0..k).try_fold(1u128, |acc, i| {
+                // binomial(n, i + 1) = binomial(n, i) (n - i)/(i + 1), where (i + 1)
+                // divides the product, so whatever of it doesn't divide binomial(n, i)
+                // divides (n - i)
+                let g = Self::u128_gcd(acc, i + 1);
+                (acc / g).checked_mul((n as u128 - i) / ((i + 1) / g))
+            })
+        };
+        let terms = self.coeffs.iter().enumerate().skip(k);
+        let coeffs = terms.map(|(n, &c)| match c {
+            0 => Some(0),
+            c => isize::try_from(binomial(n)?).ok()?.checked_mul(c),
+        });
+        coeffs
+            .collect::<Option<Vec<_>>>()
+            .map(Self::new)
+            .ok_or(PolyError::Overflow)
+    }
+
+    /// Gives the antiderivative with constant term 0, which generally has fractional
+    /// coefficients; `QPolynomial::to_parts()` splits it into an integer polynomial and a
+    /// fraction if that's wanted.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational::Rational};
+    ///
+    /// let poly = poly![1, 1, 1]; // x^2 + x + 1
+    /// let integral = poly.antiderivative();
+    /// assert_eq!(integral.to_string(), "x^3/3 + x^2/2 + x");
+    /// assert_eq!(integral.to_parts(), (poly![0, 6, 3, 2], Rational::new(1, 6)));
+    /// assert_eq!(poly![2, 6].antiderivative().to_polynomial(), Some(poly![0, 2, 3]));
+    /// ```
+    pub fn antiderivative(&self) -> QPolynomial {
+        let mut coeffs = vec![Rational::zero()];
+        for (n, &c) in self.coeffs.iter().enumerate() {
+            coeffs.push(Rational::new(c, n as isize + 1));
+        }
+        QPolynomial::new(coeffs)
+    }
+
+    /// Gives the exact definite integral of the polynomial from `a` to `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational::Rational};
+    ///
+    /// let square = poly![0, 0, 1];
+    /// assert_eq!(square.integrate(0, 1), Rational::new(1, 3));
+    /// assert_eq!(square.integrate(1, 0), Rational::new(-1, 3));
+    /// assert_eq!(poly![0, 1].integrate(-2, 2), Rational::zero());
+    /// ```
+    pub fn integrate(&self, a: isize, b: isize) -> Rational {
+        let integral = self.antiderivative();
+        integral.eval(Rational::from(b)) - integral.eval(Rational::from(a))
+    }
+
+    /// Plugs in a specific `isize` value `x` to the polynomial. This doesn't check for
+    /// overflow; `try_eval()` does.
     ///
     /// # Examples
     /// ```
@@ -100,6 +267,79 @@ impl Polynomial {
         acc
     }
 
+    /// Like `eval()`, but gives an error if the value overflows an `isize`. Since it uses the
+    /// same Horner's scheme, this includes the rare case where the value fits but one of the
+    /// partial sums along the way doesn't.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![5, 2, 1];
+    /// assert_eq!(poly.try_eval(-2), Ok(5));
+    /// assert_eq!(poly.try_eval(1 << 40), Err(PolyError::Overflow));
+    /// ```
+    pub fn try_eval(&self, x: isize) -> Result<isize, PolyError> {
+        let mut acc: isize = 0;
+        for &i in self.coeffs.iter().rev() {
+            acc = acc
+                .checked_mul(x)
+                .and_then(|acc| acc.checked_add(i))
+                .ok_or(PolyError::Overflow)?;
+        }
+        Ok(acc)
+    }
+
+    /// Plugs the polynomial `inner` into `self`, giving the composition `self(inner(x))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let outer = poly![1, 0, 1]; // x^2 + 1
+    /// let inner = poly![-1, 2]; // 2x - 1
+    ///
+    /// assert_eq!(outer.compose(&inner), poly![2, -4, 4]); // (2x - 1)^2 + 1
+    /// assert_eq!(inner.compose(&outer), poly![1, 0, 2]); // 2(x^2 + 1) - 1
+    /// assert_eq!(outer.compose(&poly![3]), poly![10]);
+    /// ```
+    pub fn compose(&self, inner: &Self) -> Self {
+        // the same Horner's scheme as in eval(), just with polynomials in place of isizes
+        let mut acc = Self::zero();
+        for &i in self.coeffs.iter().rev() {
+            acc *= inner;
+            acc += i;
+        }
+        acc
+    }
+
+    /// Gives the polynomial shifted by `a`, i.e. `self(x + a)`. This is a special case
+    /// of `compose()`, but is done directly on the coefficients in O(n^2) operations.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![0, 0, 1]; // x^2
+    /// assert_eq!(poly.taylor_shift(1), poly![1, 2, 1]); // (x + 1)^2
+    /// assert_eq!(poly.taylor_shift(-3), poly![9, -6, 1]); // (x - 3)^2
+    ///
+    /// let cubic = poly![5, -4, 0, 2];
+    /// assert_eq!(cubic.taylor_shift(7), cubic.compose(&poly![7, 1]));
+    /// ```
+    pub fn taylor_shift(&self, a: isize) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        let n = coeffs.len();
+        // each pass is one run of synthetic division by (x - a); after the k-th
+        // pass, coefficient k has its final value
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                coeffs[j] += a * coeffs[j + 1];
+            }
+        }
+        Self::new(coeffs)
+    }
+
     /// Returns `true` if `x` is a root of the polynomial; otherwise returns `false`.
     ///
     /// # Examples
@@ -178,9 +418,40 @@ impl Polynomial {
         }
     }
 
+    /// Like `factor_root()`, but says why it failed: `a` isn't a root, or evaluating at `a`
+    /// overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![12, -8, 1]; // (x - 2)(x - 6)
+    /// assert_eq!(poly.try_factor_root(2), Ok(poly![-6, 1]));
+    /// assert_eq!(poly.try_factor_root(5), Err(PolyError::NotARoot(5)));
+    /// assert_eq!(poly.try_factor_root(1 << 40), Err(PolyError::Overflow));
+    /// ```
+    pub fn try_factor_root(&self, a: isize) -> Result<Self, PolyError> {
+        if self.try_eval(a)? != 0 {
+            return Err(PolyError::NotARoot(a));
+        }
+        if a == 0 {
+            return Ok(Self::new(self.coeffs.iter().skip(1).copied().collect()));
+        }
+        // the same synthetic division as factor_root(); since a is a root, every division
+        // is exact and the accumulator stays no bigger than the coefficients
+        let mut coeffs = Vec::new();
+        let mut acc: isize = 0;
+        for &coeff in self.coeffs.iter().take(self.coeffs.len().saturating_sub(1)) {
+            acc = acc.checked_sub(coeff).ok_or(PolyError::Overflow)? / a;
+            coeffs.push(acc);
+        }
+        Ok(Self::new(coeffs))
+    }
+
     /// If `a` is a root of `self` and if `p` is a prime, this returns the
     /// result of factoring `x - a` out of `self`, if everything is considered
-    /// a polynomial with coefficients modulo `p`. Otherwise returns `None`.
+    /// a polynomial with coefficients modulo `p`. Otherwise returns `None`;
+    /// `try_factor_root_mod()` says which of the two went wrong.
     ///
     /// The API demands that `p` be prime because factoring gets more complicated
     /// when the modulus is composite, like the integers mod 4---the example below,
@@ -205,39 +476,63 @@ impl Polynomial {
     ///
     /// assert_eq!(poly2.factor_root_mod(1, 2), Some(poly![1, 1]));
     /// assert_eq!(poly2.factor_root_mod(0, 2), None);
+    ///
+    /// // squares of primes aren't prime, even though x^2 has the root 0 modulo them
+    /// let square = poly![0, 0, 1];
+    /// assert_eq!(square.factor_root_mod(0, 25), None);
+    /// assert_eq!(square.factor_root_mod(0, 49), None);
+    /// assert_eq!(square.factor_root_mod(0, 7), Some(poly![0, 1]));
     /// ```
     pub fn factor_root_mod(&self, a: isize, p: isize) -> Option<Self> {
-        // if not a root or p isn't prime, we're done
-        if !self.has_root_mod(a, p) || !Self::is_prime(p as usize) {
-            None
-        // if polynomial is zero, everything's a root, and the factoring gives zero again
-        } else if self.is_zero() {
-            Some(Self::zero())
-        // if zero is a root, then we can just skip the constant, reduce mod div, and be done
-        } else if a == 0 {
-            let mut output = Self {
-                coeffs: self.rem_euclid(p).iter().skip(1).copied().collect(),
-            };
-            output.reduce();
-            Some(output)
-        // otherwise, we know that the last coefficient b[0] of the output
-        // will be -c[0]/a where c is self's coeff vec, and b[n] = (b[n-1] - c[n])/a
-        // in general; thus the loop below does what we want---
-        } else {
-            let mut coeffs = Vec::new();
-            // keep an accumulator,
-            let mut acc = 0;
-            for &coeff in self.rem_euclid(p).iter().take(self.degree() as usize) {
-                // and at each step, subtract c[n] and divide by a
-                acc -= coeff;
-                acc *= Self::inv_mod_p(a.rem_euclid(p), p);
-                coeffs.push(acc.rem_euclid(p));
-            }
-            Some(Self { coeffs })
+        self.try_factor_root_mod(a, p).ok()
+    }
+
+    /// Like `factor_root_mod()`, but says why it failed, so that a non-root can be told
+    /// apart from a bad modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let poly = poly![12, -8, 1]; // x^2 + 2x + 2 = (x - 2)(x + 4) mod 5
+    /// assert_eq!(poly.try_factor_root_mod(2, 5), Ok(poly![4, 1]));
+    /// assert_eq!(poly.try_factor_root_mod(0, 5), Err(PolyError::NotARoot(0)));
+    /// assert_eq!(poly.try_factor_root_mod(2, 4), Err(PolyError::NonPrimeModulus(4)));
+    /// assert_eq!(poly.try_factor_root_mod(2, 0), Err(PolyError::ZeroModulus));
+    /// ```
+    pub fn try_factor_root_mod(&self, a: isize, p: isize) -> Result<Self, PolyError> {
+        if p == 0 {
+            return Err(PolyError::ZeroModulus);
+        }
+        if p < 0 || !Self::is_prime(p as usize) {
+            return Err(PolyError::NonPrimeModulus(p));
         }
+        if self.eval_mod(a, p) != 0 {
+            return Err(PolyError::NotARoot(a));
+        }
+        let reduced = self.rem_euclid(p);
+        let a = a.rem_euclid(p);
+        // if zero is a root, then we can just skip the constant and be done
+        if a == 0 {
+            return Ok(Self::new(reduced.coeffs.iter().skip(1).copied().collect()));
+        }
+        // otherwise it's the same synthetic division as factor_root(), but multiplying by
+        // the inverse of a rather than dividing, in i128 so nothing overflows
+        let inv = Self::inv_mod_p(a, p) as i128;
+        let mut coeffs = Vec::new();
+        let mut acc: i128 = 0;
+        for &coeff in reduced
+            .coeffs
+            .iter()
+            .take(reduced.coeffs.len().saturating_sub(1))
+        {
+            acc = ((acc - coeff as i128) * inv).rem_euclid(p as i128);
+            coeffs.push(acc as isize);
+        }
+        Ok(Self::new(coeffs))
     }
 
-    fn is_prime(p: usize) -> bool {
+    pub(crate) fn is_prime(p: usize) -> bool {
         if p == 2 || p == 3 {
             true
         } else if p == 1 || p % 2 == 0 || p % 3 == 0 {
@@ -245,18 +540,20 @@ impl Polynomial {
         } else {
             // we need only search for prime factors up to the sqrt of n;
             // every prime past 3 is either 1 or 5 mod 6, so we can quickly
-            // reduce our search space to a size of approx sqrt(n)/3
-            for i in (5..((p as f64).sqrt().floor() as usize)).filter(|&x| x % 6 == 1 || x % 6 == 5)
-            {
-                if p % i == 0 {
+            // reduce our search space to a size of approx sqrt(n)/3. comparing
+            // i against p / i keeps the bound exact, where a float sqrt isn't
+            let mut i = 5;
+            while i <= p / i {
+                if p % i == 0 || p % (i + 2) == 0 {
                     return false;
                 }
+                i += 6;
             }
             true
         }
     }
 
-    fn inv_mod_p(a: isize, p: isize) -> isize {
+    pub(crate) fn inv_mod_p(a: isize, p: isize) -> isize {
         let mut r_pair = (a, p);
         let mut s_pair = (1, 0);
         while r_pair.1 != 0 {
@@ -266,4 +563,28 @@ impl Polynomial {
         }
         s_pair.0.rem_euclid(p)
     }
+
+    /// Evaluates a polynomial at `x` modulo `p`, reducing as it goes so nothing overflows.
+    pub(crate) fn eval_mod(&self, x: isize, p: isize) -> isize {
+        let (x, p) = (x.rem_euclid(p) as i128, p as i128);
+        let mut acc = 0;
+        for &i in self.coeffs.iter().rev() {
+            acc = (acc * x + i as i128).rem_euclid(p);
+        }
+        acc as isize
+    }
+
+    pub(crate) fn u128_gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    /// Gives the nonnegative gcd of `a` and `b`, worked out on their magnitudes so that
+    /// `isize::MIN` is fine. The one gcd too big for an `isize` is 2^63, that of
+    /// `isize::MIN` with itself or with zero, which wraps around to `isize::MIN`.
+    pub(crate) fn int_gcd(a: isize, b: isize) -> isize {
+        Self::u128_gcd(a.unsigned_abs() as u128, b.unsigned_abs() as u128) as isize
+    }
 }
@@ -62,7 +62,7 @@ impl Mul<isize> for Polynomial {
     }
 }
 
-impl<'a> Mul<isize> for &'a Polynomial {
+impl Mul<isize> for &Polynomial {
     type Output = Polynomial;
     fn mul(self, rhs: isize) -> Self::Output {
         if self.is_zero() || rhs == 0 {
@@ -97,7 +97,7 @@ impl MulAssign for Polynomial {
     }
 }
 
-impl<'a> MulAssign<&'a Polynomial> for Polynomial {
+impl MulAssign<&Polynomial> for Polynomial {
     fn mul_assign(&mut self, rhs: &Self) {
         if self.is_zero() || rhs.is_zero() {
             *self = Polynomial::zero();
@@ -0,0 +1,508 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::format::{write_sum, Term, SPACED};
+use crate::Polynomial;
+
+/// The ways of ordering the monomials of a `MultiPolynomial`, which decide which term leads
+/// and the order `Display` writes terms in. All of them have `x0 > x1 > x2 > ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonomialOrder {
+    /// Lexicographic: compare the exponents of `x0`, then of `x1`, and so on.
+    Lex,
+    /// Graded lexicographic: compare total degrees, then break ties lexicographically.
+    GrLex,
+    /// Graded reverse lexicographic: compare total degrees, then break ties in favor of the
+    /// monomial with the smaller exponent in the last variable where they differ.
+    GrevLex,
+}
+
+impl MonomialOrder {
+    /// Compares two monomials, given as their exponent vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use polynomint::multi::MonomialOrder;
+    ///
+    /// // x y^2 against x^2 (in x, y)
+    /// assert_eq!(MonomialOrder::Lex.cmp(&[1, 2], &[2, 0]), Ordering::Less);
+    /// assert_eq!(MonomialOrder::GrLex.cmp(&[1, 2], &[2, 0]), Ordering::Greater);
+    ///
+    /// // x y z^2 against x y^3 (in x, y, z)
+    /// assert_eq!(MonomialOrder::GrLex.cmp(&[1, 1, 2], &[1, 3, 0]), Ordering::Less);
+    /// assert_eq!(MonomialOrder::GrevLex.cmp(&[1, 1, 2], &[1, 3, 0]), Ordering::Less);
+    /// assert_eq!(MonomialOrder::GrevLex.cmp(&[2, 0, 2], &[1, 3, 0]), Ordering::Less);
+    /// assert_eq!(MonomialOrder::GrLex.cmp(&[2, 0, 2], &[1, 3, 0]), Ordering::Greater);
+    /// ```
+    pub fn cmp(self, a: &[usize], b: &[usize]) -> Ordering {
+        let degree = |m: &[usize]| m.iter().sum::<usize>();
+        match self {
+            Self::Lex => a.cmp(b),
+            Self::GrLex => degree(a).cmp(&degree(b)).then_with(|| a.cmp(b)),
+            Self::GrevLex => degree(a)
+                .cmp(&degree(b))
+                .then_with(|| b.iter().rev().cmp(a.iter().rev())),
+        }
+    }
+}
+
+/// A polynomial with integer coefficients in a fixed number of variables, `x0`, `x1`, and so
+/// on, stored as its nonzero terms sorted from the leading term down by a `MonomialOrder`.
+/// Each term is the vector of exponents of its monomial along with its coefficient.
+///
+/// Combining polynomials in different numbers of variables panics; the result of an
+/// operation on two polynomials uses the monomial order of the left one.
+///
+/// # Examples
+/// ```
+/// use polynomint::multi::MultiPolynomial;
+///
+/// let x = MultiPolynomial::var(2, 0);
+/// let y = MultiPolynomial::var(2, 1);
+/// let circle = &x * &x + &y * &y - MultiPolynomial::constant(2, 1);
+///
+/// assert_eq!(circle.to_string(), "x^2 + y^2 - 1");
+/// assert_eq!(circle.eval(&[1, 0]), 0);
+/// assert_eq!(circle.total_degree(), 2);
+/// assert_eq!(circle.display_with(&["u", "v"]).to_string(), "u^2 + v^2 - 1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiPolynomial {
+    pub(crate) nvars: usize,
+    pub(crate) order: MonomialOrder,
+    pub(crate) terms: Vec<(Vec<usize>, isize)>,
+}
+
+impl PartialEq for MultiPolynomial {
+    /// Polynomials are equal if they have the same terms, whatever their monomial orders.
+    fn eq(&self, other: &Self) -> bool {
+        if self.nvars != other.nvars || self.terms.len() != other.terms.len() {
+            return false;
+        }
+        if self.order == other.order {
+            return self.terms == other.terms;
+        }
+        let mut other = other.terms.clone();
+        other.sort_by(|(a, _), (b, _)| self.order.cmp(b, a));
+        self.terms == other
+    }
+}
+
+impl Eq for MultiPolynomial {}
+
+impl MultiPolynomial {
+    /// Creates a polynomial in `nvars` variables from terms given in any order, adding
+    /// together the coefficients of repeated monomials and leaving out zeroes. The monomial
+    /// order is `Lex`; see `with_order()`. Panics if an exponent vector doesn't have length
+    /// `nvars`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::MultiPolynomial;
+    ///
+    /// let poly = MultiPolynomial::new(2, vec![(vec![0, 1], 3), (vec![2, 0], 1), (vec![0, 1], -1)]);
+    /// assert_eq!(poly.terms(), &[(vec![2, 0], 1), (vec![0, 1], 2)]);
+    /// ```
+    pub fn new(nvars: usize, terms: Vec<(Vec<usize>, isize)>) -> Self {
+        assert!(
+            terms.iter().all(|(m, _)| m.len() == nvars),
+            "exponent vector has the wrong number of variables"
+        );
+        Self::from_terms(nvars, MonomialOrder::Lex, terms)
+    }
+
+    /// Sorts and combines terms whose exponent vectors are already known to be the right
+    /// length.
+    pub(crate) fn from_terms(
+        nvars: usize,
+        order: MonomialOrder,
+        mut terms: Vec<(Vec<usize>, isize)>,
+    ) -> Self {
+        terms.sort_by(|(a, _), (b, _)| order.cmp(b, a));
+        let mut combined: Vec<(Vec<usize>, isize)> = Vec::with_capacity(terms.len());
+        for (m, c) in terms {
+            match combined.last_mut() {
+                Some(last) if last.0 == m => last.1 += c,
+                _ => combined.push((m, c)),
+            }
+        }
+        combined.retain(|&(_, c)| c != 0);
+        Self {
+            nvars,
+            order,
+            terms: combined,
+        }
+    }
+
+    /// Creates the zero polynomial in `nvars` variables.
+    pub fn zero(nvars: usize) -> Self {
+        Self {
+            nvars,
+            order: MonomialOrder::Lex,
+            terms: Vec::new(),
+        }
+    }
+
+    /// Creates the constant polynomial `c` in `nvars` variables.
+    pub fn constant(nvars: usize, c: isize) -> Self {
+        Self::from_terms(nvars, MonomialOrder::Lex, vec![(vec![0; nvars], c)])
+    }
+
+    /// Creates the polynomial `x_i` in `nvars` variables. Panics unless `i < nvars`.
+    pub fn var(nvars: usize, i: usize) -> Self {
+        assert!(i < nvars, "variable index out of range");
+        let mut m = vec![0; nvars];
+        m[i] = 1;
+        Self::from_terms(nvars, MonomialOrder::Lex, vec![(m, 1)])
+    }
+
+    /// Creates the polynomial in `nvars` variables which is `poly` in the variable `x_i`.
+    /// Panics unless `i < nvars`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, multi::MultiPolynomial};
+    ///
+    /// let poly = MultiPolynomial::from_univariate(2, 1, &poly![1, 0, 3]);
+    /// assert_eq!(poly.to_string(), "3y^2 + 1");
+    /// assert_eq!(poly.to_univariate(1), Some(poly![1, 0, 3]));
+    /// assert_eq!(poly.to_univariate(0), None);
+    /// ```
+    pub fn from_univariate(nvars: usize, i: usize, poly: &Polynomial) -> Self {
+        assert!(i < nvars, "variable index out of range");
+        let terms = poly.coeffs.iter().enumerate().map(|(n, &c)| {
+            let mut m = vec![0; nvars];
+            m[i] = n;
+            (m, c)
+        });
+        Self::from_terms(nvars, MonomialOrder::Lex, terms.collect())
+    }
+
+    /// Gives the polynomial as a univariate `Polynomial` in `x_i`, if no other variable
+    /// appears in it.
+    pub fn to_univariate(&self, i: usize) -> Option<Polynomial> {
+        let mut coeffs = Vec::new();
+        for (m, c) in self.terms.iter() {
+            if m.iter().enumerate().any(|(j, &e)| j != i && e != 0) {
+                return None;
+            }
+            if coeffs.len() <= m[i] {
+                coeffs.resize(m[i] + 1, 0);
+            }
+            coeffs[m[i]] = *c;
+        }
+        Some(Polynomial::new(coeffs))
+    }
+
+    /// Gives the same polynomial with its terms sorted by `order` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::{MonomialOrder, MultiPolynomial};
+    ///
+    /// let poly = MultiPolynomial::new(2, vec![(vec![1, 0], 1), (vec![0, 3], 1)]);
+    /// assert_eq!(poly.to_string(), "x + y^3");
+    /// assert_eq!(poly.with_order(MonomialOrder::GrLex).to_string(), "y^3 + x");
+    /// ```
+    pub fn with_order(mut self, order: MonomialOrder) -> Self {
+        self.order = order;
+        self.terms.sort_by(|(a, _), (b, _)| order.cmp(b, a));
+        self
+    }
+
+    /// Returns the number of variables.
+    pub fn nvars(&self) -> usize {
+        self.nvars
+    }
+
+    /// Returns the monomial order the terms are sorted by.
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    /// Returns the nonzero terms, from the leading term down.
+    pub fn terms(&self) -> &[(Vec<usize>, isize)] {
+        &self.terms
+    }
+
+    /// Returns the leading term under the polynomial's monomial order, or `None` for zero.
+    pub fn leading_term(&self) -> Option<(&[usize], isize)> {
+        self.terms.first().map(|(m, c)| (m.as_slice(), *c))
+    }
+
+    /// Checks whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Gives the highest total degree of any term, or -1 for the zero polynomial.
+    pub fn total_degree(&self) -> isize {
+        let degrees = self
+            .terms
+            .iter()
+            .map(|(m, _)| m.iter().sum::<usize>() as isize);
+        degrees.max().unwrap_or(-1)
+    }
+
+    /// Plugs in `point[i]` for each variable `x_i`. Panics if `point` doesn't have one
+    /// value per variable.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::MultiPolynomial;
+    ///
+    /// // x^2 y - 3z + 2
+    /// let poly = MultiPolynomial::new(3, vec![(vec![2, 1, 0], 1), (vec![0, 0, 1], -3), (vec![0, 0, 0], 2)]);
+    /// assert_eq!(poly.eval(&[2, 5, 1]), 19);
+    /// ```
+    pub fn eval(&self, point: &[isize]) -> isize {
+        assert_eq!(
+            point.len(),
+            self.nvars,
+            "point has the wrong number of variables"
+        );
+        let term = |m: &[usize], c: isize| {
+            m.iter()
+                .zip(point)
+                .fold(c, |acc, (&e, &x)| acc * x.pow(e as u32))
+        };
+        self.terms.iter().map(|(m, c)| term(m, *c)).sum()
+    }
+
+    /// Gives the partial derivative with respect to `x_i`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::multi::MultiPolynomial;
+    ///
+    /// // x^2 y^3 + 4y
+    /// let poly = MultiPolynomial::new(2, vec![(vec![2, 3], 1), (vec![0, 1], 4)]);
+    /// assert_eq!(poly.partial_derivative(0).to_string(), "2xy^3");
+    /// assert_eq!(poly.partial_derivative(1).to_string(), "3x^2y^2 + 4");
+    /// ```
+    pub fn partial_derivative(&self, i: usize) -> Self {
+        assert!(i < self.nvars, "variable index out of range");
+        let terms = self.terms.iter().filter(|(m, _)| m[i] > 0).map(|(m, c)| {
+            let mut lowered = m.clone();
+            lowered[i] -= 1;
+            (lowered, c * m[i] as isize)
+        });
+        Self::from_terms(self.nvars, self.order, terms.collect())
+    }
+
+    /// Substitutes `poly`, as a polynomial in the variable `x_j`, for the variable `x_i`;
+    /// `j` can be `i` itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, multi::MultiPolynomial};
+    ///
+    /// // x y + y^2, then y = x^2 + 1
+    /// let poly = MultiPolynomial::new(2, vec![(vec![1, 1], 1), (vec![0, 2], 1)]);
+    /// assert_eq!(poly.substitute(1, &poly![1, 0, 1], 0).to_string(), "x^4 + x^3 + 2x^2 + x + 1");
+    /// // or y = y - 1
+    /// assert_eq!(poly.substitute(1, &poly![-1, 1], 1).to_string(), "xy - x + y^2 - 2y + 1");
+    /// ```
+    pub fn substitute(&self, i: usize, poly: &Polynomial, j: usize) -> Self {
+        assert!(
+            i < self.nvars && j < self.nvars,
+            "variable index out of range"
+        );
+        let poly = Self::from_univariate(self.nvars, j, poly).with_order(self.order);
+        let mut acc = Self::zero(self.nvars).with_order(self.order);
+        // powers of poly, computed as they're needed
+        let mut powers = vec![Self::constant(self.nvars, 1)];
+        for (m, c) in self.terms.iter() {
+            while powers.len() <= m[i] {
+                let next = &powers[powers.len() - 1] * &poly;
+                powers.push(next);
+            }
+            let mut rest = m.clone();
+            rest[i] = 0;
+            let rest = Self::from_terms(self.nvars, self.order, vec![(rest, *c)]);
+            acc = acc + &rest * &powers[m[i]];
+        }
+        acc
+    }
+
+    /// Raises the polynomial to the power `e` by repeated squaring.
+    pub fn pow(&self, mut e: u32) -> Self {
+        let mut acc = Self::constant(self.nvars, 1).with_order(self.order);
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = &acc * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// Wraps the polynomial in something which writes it out with the variables named
+    /// `vars` instead of the defaults. Names longer than one character get written with
+    /// explicit `*`s between factors. Panics if there isn't one name per variable.
+    pub fn display_with<'a>(&'a self, vars: &'a [&'a str]) -> MultiDisplay<'a> {
+        assert_eq!(vars.len(), self.nvars, "wrong number of variable names");
+        MultiDisplay { poly: self, vars }
+    }
+
+    fn check_nvars(&self, other: &Self) {
+        assert_eq!(
+            self.nvars, other.nvars,
+            "polynomials have different numbers of variables"
+        );
+    }
+}
+
+impl From<&Polynomial> for MultiPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        Self::from_univariate(1, 0, poly)
+    }
+}
+
+impl From<Polynomial> for MultiPolynomial {
+    fn from(poly: Polynomial) -> Self {
+        Self::from(&poly)
+    }
+}
+
+/// A `MultiPolynomial` together with names for its variables, as returned by
+/// `MultiPolynomial::display_with()`.
+#[derive(Debug, Clone, Copy)]
+pub struct MultiDisplay<'a> {
+    poly: &'a MultiPolynomial,
+    vars: &'a [&'a str],
+}
+
+impl std::fmt::Display for MultiDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.poly.is_zero() {
+            return write!(f, "0");
+        }
+        let mul = if self.vars.iter().all(|v| v.chars().count() == 1) {
+            ""
+        } else {
+            "*"
+        };
+        let terms = self.poly.terms.iter();
+        let terms = terms.map(|(m, c)| Term::new(*c, m.iter().all(|&e| e == 0), m));
+        write_sum(f, SPACED, terms, |f, coeff, m| {
+            if let Some(abs) = coeff {
+                write!(f, "{}", abs)?;
+            }
+            let mut first = coeff.is_none();
+            for (var, &e) in self.vars.iter().zip(m.iter()) {
+                if e == 0 {
+                    continue;
+                }
+                if !first {
+                    write!(f, "{}", mul)?;
+                }
+                write!(f, "{}", var)?;
+                if e > 1 {
+                    write!(f, "^{}", e)?;
+                }
+                first = false;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl std::fmt::Display for MultiPolynomial {
+    /// Writes the polynomial with its variables named `x`, `y` and `z` if there are at most
+    /// three of them, and `x0`, `x1`, ... otherwise.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.nvars <= 3 {
+            write!(f, "{}", self.display_with(&["x", "y", "z"][..self.nvars]))
+        } else {
+            let names: Vec<String> = (0..self.nvars).map(|i| format!("x{}", i)).collect();
+            let names: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+            write!(f, "{}", self.display_with(&names))
+        }
+    }
+}
+
+impl Add for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_nvars(rhs);
+        let terms = self.terms.iter().chain(rhs.terms.iter()).cloned().collect();
+        MultiPolynomial::from_terms(self.nvars, self.order, terms)
+    }
+}
+
+impl Add for MultiPolynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sub for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_nvars(rhs);
+        let negated = rhs.terms.iter().map(|(m, c)| (m.clone(), -c));
+        let terms = self.terms.iter().cloned().chain(negated).collect();
+        MultiPolynomial::from_terms(self.nvars, self.order, terms)
+    }
+}
+
+impl Sub for MultiPolynomial {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Neg for MultiPolynomial {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        for term in self.terms.iter_mut() {
+            term.1 = -term.1;
+        }
+        self
+    }
+}
+
+impl Mul for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_nvars(rhs);
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for (a, c) in self.terms.iter() {
+            for (b, d) in rhs.terms.iter() {
+                let m = a.iter().zip(b.iter()).map(|(i, j)| i + j).collect();
+                terms.push((m, c * d));
+            }
+        }
+        MultiPolynomial::from_terms(self.nvars, self.order, terms)
+    }
+}
+
+impl Mul for MultiPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<isize> for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn mul(self, rhs: isize) -> Self::Output {
+        let terms = self.terms.iter().map(|(m, c)| (m.clone(), c * rhs));
+        MultiPolynomial::from_terms(self.nvars, self.order, terms.collect())
+    }
+}
+
+impl Mul<isize> for MultiPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self::Output {
+        &self * rhs
+    }
+}
@@ -0,0 +1,336 @@
+use crate::error::PolyError;
+use crate::Polynomial;
+
+/// Below this many coefficients, multiplication is done schoolbook-style rather than
+/// with Karatsuba's trick.
+const KARATSUBA_CUTOFF: usize = 32;
+
+/// Below this many points, evaluating the polynomial at each point with Horner's scheme
+/// is faster than going through a subproduct tree.
+const DIRECT_EVAL_CUTOFF: usize = 16;
+
+/// A subproduct tree over a fixed list of points `x_0, ..., x_{n-1}`: a binary tree whose
+/// leaves are the linear polynomials `x - x_i` and whose every other node is the product
+/// of its two children, so that the root is the product of all the `x - x_i`.
+///
+/// Evaluating a polynomial at all of the points is done by reducing it modulo the root and
+/// then taking remainders on the way down the tree, which (together with fast
+/// multiplication and remainders by Newton iteration) needs far fewer operations than
+/// evaluating at each point separately. The tree only depends on the points, so it can be
+/// built once and reused for any number of polynomials.
+///
+/// The tree can either work over the integers or with everything reduced modulo some `p`.
+/// Over the integers, the coefficients of the tree outgrow an `isize` quickly; they're
+/// computed with wrapping arithmetic, which still gives the right values in the end as
+/// long as those values themselves fit in an `isize`.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, multipoint::SubproductTree};
+///
+/// let tree = SubproductTree::new(&[0, 1, 2, -1]);
+/// assert_eq!(tree.root(), &poly![0, 2, -1, -2, 1]); // x(x - 1)(x - 2)(x + 1)
+///
+/// let poly = poly![3, 0, 1]; // x^2 + 3
+/// assert_eq!(tree.eval(&poly), vec![3, 4, 7, 4]);
+/// let other = poly![1, 1, 1, 1, 1, 1];
+/// assert_eq!(tree.eval(&other), vec![1, 6, 63, 0]);
+///
+/// let tree_mod = SubproductTree::new_mod(&[0, 1, 2, 3, 4], 5);
+/// assert_eq!(tree_mod.root(), &poly![0, 4, 0, 0, 0, 1]); // x^5 - x, mod 5
+/// assert_eq!(tree_mod.eval(&poly), vec![3, 4, 2, 2, 4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubproductTree {
+    points: Vec<isize>,
+    modulus: Option<isize>,
+    // layers[0] holds the leaves, and each later layer holds the products of adjacent
+    // pairs from the layer before it (with an unpaired last node carried up as is), so
+    // the last layer holds just the root
+    layers: Vec<Vec<Polynomial>>,
+    // inverses[k][i] is the inverse, as a power series, of the reversal of
+    // layers[k][i], to just enough precision to divide its parent's remainder by it
+    inverses: Vec<Vec<Vec<isize>>>,
+}
+
+impl SubproductTree {
+    /// Builds the subproduct tree for `points` over the integers.
+    pub fn new(points: &[isize]) -> Self {
+        Self::build(points, None)
+    }
+
+    /// Builds the subproduct tree for `points` with all coefficients taken modulo `p`;
+    /// evaluations done with this tree are then likewise reduced modulo `p`, landing in
+    /// the range `0..p`. Panics if `p` is less than 2.
+    pub fn new_mod(points: &[isize], p: isize) -> Self {
+        assert!(p >= 2, "modulus {} is less than 2", p);
+        Self::build(points, Some(p))
+    }
+
+    fn build(points: &[isize], modulus: Option<isize>) -> Self {
+        let mut layers = Vec::new();
+        let mut layer: Vec<Polynomial> = points
+            .iter()
+            .map(|&x| Polynomial::new(vec![norm(-(x as i128), modulus), 1]))
+            .collect();
+        if layer.is_empty() {
+            layer.push(Polynomial::constant(1));
+        }
+        while layer.len() > 1 {
+            let next = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => Polynomial::new(mul(&a.coeffs, &b.coeffs, modulus)),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            layers.push(layer);
+            layer = next;
+        }
+        layers.push(layer);
+
+        let mut inverses = Vec::new();
+        for k in 0..layers.len() {
+            let mut layer_inverses = Vec::new();
+            for (i, node) in layers[k].iter().enumerate() {
+                // the root has no parent, so its inverse is computed as needed in eval()
+                let prec = if k + 1 < layers.len() {
+                    (layers[k + 1][i / 2].degree() - node.degree()) as usize
+                } else {
+                    0
+                };
+                layer_inverses.push(inverse_of_reversal(node, prec, modulus));
+            }
+            inverses.push(layer_inverses);
+        }
+        Self {
+            points: points.to_vec(),
+            modulus,
+            layers,
+            inverses,
+        }
+    }
+
+    /// Returns the points this tree was built for.
+    pub fn points(&self) -> &[isize] {
+        &self.points
+    }
+
+    /// Returns the root of the tree, i.e. the product of `x - x_i` over all the points.
+    /// If the tree was built with no points, this is the constant 1.
+    pub fn root(&self) -> &Polynomial {
+        &self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Evaluates `poly` at every point of the tree, returning the values in the same
+    /// order as the points.
+    pub fn eval(&self, poly: &Polynomial) -> Vec<isize> {
+        if self.points.is_empty() {
+            return Vec::new();
+        }
+        let coeffs: Vec<isize> = poly
+            .coeffs
+            .iter()
+            .map(|&c| norm(c as i128, self.modulus))
+            .collect();
+        let root = self.root();
+        let root_inv = inverse_of_reversal(
+            root,
+            (coeffs.len() as isize - root.degree()).max(0) as usize,
+            self.modulus,
+        );
+        let mut rems = vec![rem(&coeffs, root, &root_inv, self.modulus)];
+        // walk down from the root, replacing each remainder by its remainders modulo
+        // the two children, until we're left with remainders modulo each x - x_i,
+        // which are exactly the values at the x_i
+        for k in (0..self.layers.len() - 1).rev() {
+            rems = self.layers[k]
+                .iter()
+                .zip(self.inverses[k].iter())
+                .enumerate()
+                .map(|(i, (node, inv))| rem(&rems[i / 2], node, inv, self.modulus))
+                .collect();
+        }
+        rems.into_iter()
+            .map(|r| r.first().copied().unwrap_or(0))
+            .collect()
+    }
+}
+
+impl Polynomial {
+    /// Evaluates the polynomial at each of `points`. For many points this goes through a
+    /// `SubproductTree`; if you're evaluating several polynomials at the same points,
+    /// build the tree yourself and use `SubproductTree::eval()` instead.
+    ///
+    /// Values that don't fit in an `isize` wrap around, however many points there are,
+    /// since the subproduct tree can only work that way; `try_eval_many()` reports them
+    /// as errors instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let poly = poly![1, -1, 0, 1]; // x^3 - x + 1
+    /// let points: Vec<isize> = (-20..20).collect();
+    /// let values: Vec<isize> = points.iter().map(|&x| poly.eval(x)).collect();
+    /// assert_eq!(poly.eval_many(&points), values);
+    ///
+    /// // (2^32)^2 wraps around to 0 with a few points or with many
+    /// let square = poly![0, 0, 1];
+    /// assert_eq!(square.eval_many(&[3, 1 << 32]), vec![9, 0]);
+    /// let mut points: Vec<isize> = (0..20).collect();
+    /// points.push(1 << 32);
+    /// assert_eq!(square.eval_many(&points)[20], 0);
+    /// ```
+    pub fn eval_many(&self, points: &[isize]) -> Vec<isize> {
+        if points.len() < DIRECT_EVAL_CUTOFF {
+            points
+                .iter()
+                .map(|&x| {
+                    self.coeffs
+                        .iter()
+                        .rev()
+                        .fold(0isize, |acc, &c| acc.wrapping_mul(x).wrapping_add(c))
+                })
+                .collect()
+        } else {
+            SubproductTree::new(points).eval(self)
+        }
+    }
+
+    /// Like `eval_many()`, but gives an error if any of the values overflows an `isize`,
+    /// in the same way as `try_eval()`. This evaluates at each point in turn, since the
+    /// subproduct tree can't tell when something has overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, error::PolyError};
+    ///
+    /// let square = poly![0, 0, 1];
+    /// assert_eq!(square.try_eval_many(&[-3, 3]), Ok(vec![9, 9]));
+    /// assert_eq!(square.try_eval_many(&[3, 1 << 32]), Err(PolyError::Overflow));
+    /// ```
+    pub fn try_eval_many(&self, points: &[isize]) -> Result<Vec<isize>, PolyError> {
+        points.iter().map(|&x| self.try_eval(x)).collect()
+    }
+
+    /// Evaluates the polynomial at each of `points`, modulo `p`; each value is in the
+    /// range `0..p`. Panics if `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let p = 65537;
+    /// let poly = Polynomial::new((1..=200).collect()); // 200x^199 + ... + 2x + 1
+    /// let points: Vec<isize> = (0..1000).map(|i| i * i + 1).collect();
+    /// let values = poly.eval_many_mod(&points, p);
+    ///
+    /// assert_eq!(values[0], 20100 % p); // at x = 1, it's 1 + 2 + ... + 200
+    /// for (&x, &value) in points.iter().zip(values.iter()) {
+    ///     assert_eq!(value, poly.iter().rev().fold(0, |acc, &c| (acc * x + c) % p));
+    /// }
+    /// ```
+    pub fn eval_many_mod(&self, points: &[isize], p: isize) -> Vec<isize> {
+        SubproductTree::new_mod(points, p).eval(self)
+    }
+}
+
+/// Reduces a coefficient modulo `modulus` into `0..modulus` if there is one, and otherwise
+/// wraps it back down to an `isize` (which is fine, since everything done with it
+/// afterwards is ring arithmetic and so commutes with wrapping).
+fn norm(c: i128, modulus: Option<isize>) -> isize {
+    match modulus {
+        Some(p) => c.rem_euclid(p as i128) as isize,
+        None => c as isize,
+    }
+}
+
+/// Multiplies two coefficient vectors, using Karatsuba's trick above `KARATSUBA_CUTOFF`.
+fn mul(a: &[isize], b: &[isize], modulus: Option<isize>) -> Vec<isize> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_CUTOFF {
+        let mut out = vec![0i128; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                out[i + j] = norm(out[i + j] + x as i128 * y as i128, modulus) as i128;
+            }
+        }
+        return out.into_iter().map(|c| norm(c, modulus)).collect();
+    }
+    // write a = a0 + x^h a1 and b = b0 + x^h b1; then
+    // ab = a0 b0 + x^h ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) + x^2h a1 b1
+    let h = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(h.min(a.len()));
+    let (b0, b1) = b.split_at(h.min(b.len()));
+    let low = mul(a0, b0, modulus);
+    let high = mul(a1, b1, modulus);
+    let mid = mul(&add(a0, a1, 1, modulus), &add(b0, b1, 1, modulus), modulus);
+    let mid = add(&add(&mid, &low, -1, modulus), &high, -1, modulus);
+    let mut out = vec![0; a.len() + b.len() - 1];
+    for (i, &c) in low.iter().enumerate() {
+        out[i] += c as i128;
+    }
+    for (i, &c) in mid.iter().enumerate() {
+        out[i + h] += c as i128;
+    }
+    for (i, &c) in high.iter().enumerate() {
+        out[i + 2 * h] += c as i128;
+    }
+    out.into_iter().map(|c| norm(c, modulus)).collect()
+}
+
+/// Gives `a + sign * b` for coefficient vectors `a` and `b`.
+fn add(a: &[isize], b: &[isize], sign: isize, modulus: Option<isize>) -> Vec<isize> {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let x = a.get(i).copied().unwrap_or(0) as i128;
+            let y = b.get(i).copied().unwrap_or(0) as i128;
+            norm(x + sign as i128 * y, modulus)
+        })
+        .collect()
+}
+
+/// Computes the power series inverse of the reversal of the monic polynomial `m`
+/// (i.e. `x^deg(m) m(1/x)`, whose constant term is 1), modulo `x^prec`, by Newton
+/// iteration: if `g` is the inverse of `f` modulo `x^l`, then `g (2 - f g)` is its
+/// inverse modulo `x^2l`.
+fn inverse_of_reversal(m: &Polynomial, prec: usize, modulus: Option<isize>) -> Vec<isize> {
+    if prec == 0 {
+        return Vec::new();
+    }
+    let rev: Vec<isize> = m.coeffs.iter().rev().copied().collect();
+    let mut inv = vec![1];
+    let mut l = 1;
+    while l < prec {
+        l = (2 * l).min(prec);
+        let mut fg = mul(&rev[..l.min(rev.len())], &inv, modulus);
+        fg.truncate(l);
+        let mut two_minus_fg: Vec<isize> =
+            fg.iter().map(|&c| norm(-(c as i128), modulus)).collect();
+        two_minus_fg[0] = norm(two_minus_fg[0] as i128 + 2, modulus);
+        inv = mul(&inv, &two_minus_fg, modulus);
+        inv.truncate(l);
+    }
+    inv
+}
+
+/// Gives the remainder of `a` modulo the monic polynomial `m`, given `inv`, the inverse
+/// of the reversal of `m` to at least `a.len() - deg(m)` terms. The quotient's reversal
+/// is the reversal of `a` times `inv`, truncated, so this takes just two multiplications.
+fn rem(a: &[isize], m: &Polynomial, inv: &[isize], modulus: Option<isize>) -> Vec<isize> {
+    let d = m.degree() as usize;
+    if a.len() <= d {
+        return a.to_vec();
+    }
+    let k = a.len() - d;
+    let rev_a: Vec<isize> = a.iter().rev().take(k).copied().collect();
+    let mut q = mul(&rev_a, &inv[..k.min(inv.len())], modulus);
+    q.resize(k, 0);
+    q.reverse();
+    let mut r = add(a, &mul(&q, &m.coeffs, modulus), -1, modulus);
+    r.truncate(d);
+    r
+}
@@ -0,0 +1,288 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::Polynomial;
+
+/// The highest power the parser accepts, since parsing allocates a coefficient for every
+/// power up to it; a short string like `x^99999999999` could otherwise ask for any amount
+/// of memory. At this bound, a single term costs at most a megabyte.
+pub const MAX_PARSE_DEGREE: usize = 1 << 16;
+
+/// An error from parsing a polynomial, along with the byte offset into the input at
+/// which it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+/// The kinds of things that can go wrong when parsing a polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input has no terms at all.
+    Empty,
+    /// The input ended where more was expected, like after a `+` or a `^`.
+    UnexpectedEnd,
+    /// This character doesn't make sense where it appears.
+    UnexpectedChar(char),
+    /// A coefficient, or the sum of the coefficients of repeated terms, doesn't fit in an
+    /// `isize`. Only the final sum has to fit, so `x + x - x` is fine however big `x` is.
+    CoefficientOverflow,
+    /// An exponent is above `MAX_PARSE_DEGREE`.
+    ExponentOverflow,
+    /// The variable name that was asked for is empty or starts with a digit, sign or space.
+    InvalidVariable,
+    /// A modulus is missing, less than 2, or doesn't fit in an `isize`.
+    InvalidModulus,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    /// Moves the error's position `by` bytes later, for when the string it came from was
+    /// part of a bigger one.
+    pub(crate) fn offset(mut self, by: usize) -> Self {
+        self.position += by;
+        self
+    }
+
+    /// Returns what went wrong.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte offset into the input at which it went wrong.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Empty => write!(f, "no polynomial found")?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c)?,
+            ParseErrorKind::CoefficientOverflow => write!(f, "coefficient out of range")?,
+            ParseErrorKind::ExponentOverflow => write!(f, "exponent out of range")?,
+            ParseErrorKind::InvalidVariable => write!(f, "invalid variable name")?,
+            ParseErrorKind::InvalidModulus => write!(f, "invalid modulus")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Polynomial {
+    type Err = ParseError;
+
+    /// Parses a polynomial in the variable `x`; see `Polynomial::parse_with_var()`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_var(s, "x")
+    }
+}
+
+impl Polynomial {
+    /// Parses a polynomial written as a sum of terms in the variable `var`. This accepts
+    /// everything `Display` writes, and is lenient about the rest: spaces are optional,
+    /// coefficients can be separated from the variable by `*`, powers can be written with
+    /// `^` or `**`, coefficients of 1 can be left out, and terms can come in any order
+    /// (repeated powers are added together).
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, parse::ParseErrorKind};
+    ///
+    /// let poly = poly![-5, 2, 0, -1];
+    /// assert_eq!(poly.to_string().parse(), Ok(poly.clone()));
+    /// assert_eq!("2*x - x**3 - 5".parse(), Ok(poly.clone()));
+    /// assert_eq!("-5+x+x-x^3".parse(), Ok(poly));
+    /// assert_eq!(Polynomial::parse_with_var("t^2 + 2 t + 1", "t"), Ok(poly![1, 2, 1]));
+    ///
+    /// let err = "3x^2 + + 1".parse::<Polynomial>().unwrap_err();
+    /// assert_eq!(err.kind(), &ParseErrorKind::UnexpectedChar('+'));
+    /// assert_eq!(err.position(), 7);
+    ///
+    /// // the most negative coefficient survives a round trip
+    /// let min = poly![isize::MIN, isize::MIN];
+    /// assert_eq!(min.to_string().parse(), Ok(min));
+    ///
+    /// // like terms only have to fit once they're all added up
+    /// let max = format!("{}x", isize::MAX);
+    /// assert_eq!(format!("{} + x - x", max).parse(), Ok(poly![0, isize::MAX]));
+    /// let err = format!("{} + x", max).parse::<Polynomial>().unwrap_err();
+    /// assert_eq!(err.kind(), &ParseErrorKind::CoefficientOverflow);
+    /// assert_eq!(err.position(), 21);
+    ///
+    /// // exponents past MAX_PARSE_DEGREE are rejected rather than allocated
+    /// let err = "x^18446744073709551615".parse::<Polynomial>().unwrap_err();
+    /// assert_eq!(err.kind(), &ParseErrorKind::ExponentOverflow);
+    /// assert_eq!(err.position(), 2);
+    /// let err = "1 + x^99999999999".parse::<Polynomial>().unwrap_err();
+    /// assert_eq!(err.kind(), &ParseErrorKind::ExponentOverflow);
+    /// ```
+    pub fn parse_with_var(s: &str, var: &str) -> Result<Self, ParseError> {
+        match var.chars().next() {
+            Some(c) if !(c.is_ascii_digit() || c.is_whitespace() || "+-*^".contains(c)) => {}
+            _ => return Err(ParseError::new(ParseErrorKind::InvalidVariable, 0)),
+        }
+        let mut parser = Parser { s, pos: 0, var };
+        // like terms are summed in i128 and only checked against isize at the end, so that
+        // a running sum can pass out of range as long as it comes back; positions holds
+        // where the last term of each power started, to point the error at
+        let mut coeffs: Vec<i128> = Vec::new();
+        let mut positions: Vec<usize> = Vec::new();
+        parser.skip_space();
+        if parser.peek().is_none() {
+            return Err(ParseError::new(ParseErrorKind::Empty, 0));
+        }
+        let mut first = true;
+        loop {
+            parser.skip_space();
+            let start = parser.pos;
+            // every term but the first must be preceded by a + or -; the first one can
+            // optionally have a sign too
+            let negative = match parser.peek() {
+                Some('+') => {
+                    parser.bump();
+                    false
+                }
+                Some('-') => {
+                    parser.bump();
+                    true
+                }
+                Some(c) if !first => return Err(parser.error(ParseErrorKind::UnexpectedChar(c))),
+                _ => false,
+            };
+            parser.skip_space();
+            let (coeff, power) = parser.term()?;
+            if coeffs.len() <= power {
+                coeffs.resize(power + 1, 0);
+                positions.resize(power + 1, 0);
+            }
+            coeffs[power] = i128::try_from(coeff)
+                .ok()
+                .map(|c| if negative { -c } else { c })
+                .and_then(|c| coeffs[power].checked_add(c))
+                .ok_or_else(|| ParseError::new(ParseErrorKind::CoefficientOverflow, start))?;
+            positions[power] = start;
+            first = false;
+            parser.skip_space();
+            if parser.peek().is_none() {
+                let coeffs = coeffs.into_iter().zip(positions).map(|(c, at)| {
+                    isize::try_from(c)
+                        .map_err(|_| ParseError::new(ParseErrorKind::CoefficientOverflow, at))
+                });
+                return coeffs.collect::<Result<_, _>>().map(Self::new);
+            }
+        }
+    }
+}
+
+/// A cursor into the string being parsed.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+    var: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.s[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.pos)
+    }
+
+    /// Gives an error for whatever is at the cursor: the character there, or the end.
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Skips over a run of digits, returning them, or `None` if there are none.
+    fn digits(&mut self) -> Option<&'a str> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+        if self.pos == start {
+            None
+        } else {
+            Some(&self.s[start..self.pos])
+        }
+    }
+
+    /// Parses an unsigned term like `3`, `3x`, `3 * x ** 2` or `x^2`, giving the magnitude
+    /// of its coefficient and its power.
+    fn term(&mut self) -> Result<(u128, usize), ParseError> {
+        let start = self.pos;
+        let coeff = match self.digits() {
+            Some(digits) => Some(
+                digits
+                    .parse()
+                    .map_err(|_| ParseError::new(ParseErrorKind::CoefficientOverflow, start))?,
+            ),
+            None => None,
+        };
+        self.skip_space();
+        let before_times = self.pos;
+        if coeff.is_some() && self.peek() == Some('*') && !self.s[self.pos..].starts_with("**") {
+            self.bump();
+            self.skip_space();
+            if !self.s[self.pos..].starts_with(self.var) {
+                return Err(self.unexpected());
+            }
+        }
+        if !self.eat(self.var) {
+            self.pos = before_times;
+            return match coeff {
+                Some(c) => Ok((c, 0)),
+                None => Err(self.unexpected()),
+            };
+        }
+        self.skip_space();
+        let power = if self.eat("**") || self.eat("^") {
+            self.skip_space();
+            let start = self.pos;
+            match self.digits() {
+                Some(digits) => digits
+                    .parse()
+                    .ok()
+                    .filter(|&power| power <= MAX_PARSE_DEGREE)
+                    .ok_or_else(|| ParseError::new(ParseErrorKind::ExponentOverflow, start))?,
+                None => return Err(self.unexpected()),
+            }
+        } else {
+            1
+        };
+        Ok((coeff.unwrap_or(1), power))
+    }
+}
@@ -0,0 +1,211 @@
+use crate::qpoly::QPolynomial;
+use crate::rational::Rational;
+use crate::rational_function::RationalFunction;
+use crate::Polynomial;
+
+/// One term `numer / (denom * factor^power)` of a partial fraction decomposition, where
+/// `numer` has lower degree than `factor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialFraction {
+    /// The numerator, with no constant factor in common with `denom`.
+    pub numer: Polynomial,
+    /// The positive constant that clears the fractions out of the numerator.
+    pub denom: isize,
+    /// The factor of the original denominator, primitive with positive leading coefficient.
+    pub factor: Polynomial,
+    /// The power `factor` is raised to, from 1 up to its multiplicity.
+    pub power: usize,
+}
+
+impl PartialFraction {
+    /// Gives the term as a single rational function.
+    pub fn to_rational_function(&self) -> RationalFunction {
+        let den = self.factor.pow(self.power as u32) * self.denom;
+        RationalFunction::new(self.numer.clone(), den)
+    }
+}
+
+impl std::fmt::Display for PartialFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // anything with more than one term needs parentheses around it, as does a
+        // power of anything besides a bare x
+        let terms = |p: &Polynomial| p.coeffs.iter().filter(|&&c| c != 0).count();
+        if terms(&self.numer) > 1 {
+            write!(f, "({})/", self.numer)?;
+        } else {
+            write!(f, "{}/", self.numer)?;
+        }
+        if self.denom != 1 {
+            write!(f, "({}", self.denom)?;
+        }
+        if terms(&self.factor) > 1
+            || (self.denom != 1 || self.power > 1) && self.factor.coeffs != [0, 1]
+        {
+            write!(f, "({})", self.factor)?;
+        } else {
+            write!(f, "{}", self.factor)?;
+        }
+        if self.power > 1 {
+            write!(f, "^{}", self.power)?;
+        }
+        if self.denom != 1 {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// A rational function written as a polynomial part plus a sum of proper fractions over
+/// powers of factors of its denominator, as returned by
+/// `RationalFunction::partial_fractions()` and `RationalFunction::linear_partial_fractions()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialFractions {
+    /// The polynomial part, a polynomial over a constant denominator.
+    pub whole: RationalFunction,
+    /// The fractions, grouped by factor in order of increasing power.
+    pub terms: Vec<PartialFraction>,
+}
+
+impl PartialFractions {
+    /// Adds the decomposition back up into a single rational function.
+    pub fn sum(&self) -> RationalFunction {
+        self.terms.iter().fold(self.whole.clone(), |acc, term| {
+            &acc + &term.to_rational_function()
+        })
+    }
+}
+
+impl std::fmt::Display for PartialFractions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut plus_flag = false;
+        if !self.whole.is_zero() || self.terms.is_empty() {
+            write!(f, "{}", self.whole)?;
+            plus_flag = true;
+        }
+        for term in self.terms.iter() {
+            // pull the sign of a term out front so it reads as subtraction
+            let negative = term.numer.coeffs.last().is_some_and(|&lead| lead < 0);
+            match (plus_flag, negative) {
+                (true, true) => write!(f, " - ")?,
+                (true, false) => write!(f, " + ")?,
+                (false, true) => write!(f, "-")?,
+                (false, false) => {}
+            }
+            if negative {
+                let flipped = PartialFraction {
+                    numer: -&term.numer,
+                    ..term.clone()
+                };
+                write!(f, "{}", flipped)?;
+            } else {
+                write!(f, "{}", term)?;
+            }
+            plus_flag = true;
+        }
+        Ok(())
+    }
+}
+
+impl RationalFunction {
+    /// Decomposes the rational function into partial fractions over the rationals, with
+    /// respect to the square-free factorization of the denominator: if that's
+    /// `c * f_1 * f_2^2 * ...`, this gives a polynomial plus terms `a_ij / f_i^j` for
+    /// `j` up to `i`, each with `deg a_ij < deg f_i`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational_function::RationalFunction};
+    ///
+    /// // (x^3 + 1)/(x^2 (x - 1)^2)
+    /// let f = RationalFunction::new(poly![1, 0, 0, 1], poly![0, 0, 1, -2, 1]);
+    /// let fractions = f.partial_fractions();
+    /// assert_eq!(fractions.to_string(), "(x + 1)/(x^2 - x) + (x + 1)/(x^2 - x)^2");
+    /// assert_eq!(fractions.sum(), f);
+    /// ```
+    pub fn partial_fractions(&self) -> PartialFractions {
+        let den = self.denom();
+        self.decompose(den.square_free_decomposition(), den.content())
+    }
+
+    /// Decomposes the rational function into partial fractions over the rationals, with
+    /// respect to the linear factors of the denominator, if it splits into them; otherwise
+    /// gives `None`. If the denominator is `c * (a_1 x - b_1)^e_1 * ...`, this gives a
+    /// polynomial plus constants over the powers `(a_i x - b_i)^j`, for `j` up to `e_i`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational_function::RationalFunction};
+    ///
+    /// // the generating function 1/(1 - 3x + 2x^2) = 1/((x - 1)(2x - 1)) of 2^(n+1) - 1
+    /// let f = RationalFunction::new(poly![1], poly![1, -3, 2]);
+    /// let fractions = f.linear_partial_fractions().unwrap();
+    /// assert_eq!(fractions.to_string(), "1/(x - 1) - 2/(2x - 1)");
+    ///
+    /// let g = RationalFunction::new(poly![0, 0, 0, 1], poly![-1, 0, 1]); // x^3/(x^2 - 1)
+    /// let fractions = g.linear_partial_fractions().unwrap();
+    /// assert_eq!(fractions.to_string(), "x + 1/(2(x - 1)) + 1/(2(x + 1))");
+    ///
+    /// let h = RationalFunction::new(poly![1], poly![1, 0, 1]); // 1/(x^2 + 1)
+    /// assert_eq!(h.linear_partial_fractions(), None);
+    /// ```
+    pub fn linear_partial_fractions(&self) -> Option<PartialFractions> {
+        let factorization = self.denom().factor();
+        if factorization
+            .factors
+            .iter()
+            .any(|(factor, _)| factor.degree() != 1)
+        {
+            return None;
+        }
+        Some(self.decompose(factorization.factors, factorization.content))
+    }
+
+    /// Decomposes over `factors`, given as pairwise coprime primitive polynomials with
+    /// multiplicities, whose product times `constant` is the denominator.
+    fn decompose(&self, factors: Vec<(Polynomial, usize)>, constant: isize) -> PartialFractions {
+        let scale = Rational::new(1, constant);
+        let num = QPolynomial::from_parts(self.numer(), scale);
+        let den = QPolynomial::from_parts(self.denom(), scale);
+        let (whole, rem) = num.div_rem(&den).unwrap();
+        let mut terms = Vec::new();
+        for (factor, mult) in factors {
+            let factor_q = QPolynomial::from(&factor);
+            let modulus = factor_q.pow(mult as u32);
+            let (cofactor, _) = den.div_rem(&modulus).unwrap();
+            // the numerator over factor^mult is rem / cofactor taken modulo factor^mult
+            let inv = cofactor.inv_mod(&modulus).unwrap();
+            let (_, mut numer) = (&rem * &inv).div_rem(&modulus).unwrap();
+            // writing it in base factor splits it up over the powers of factor
+            let mut digits = Vec::with_capacity(mult);
+            for _ in 0..mult {
+                let (quot, digit) = numer.div_rem(&factor_q).unwrap();
+                digits.push(digit);
+                numer = quot;
+            }
+            for (power, digit) in (1..=mult).zip(digits.into_iter().rev()) {
+                if digit.is_zero() {
+                    continue;
+                }
+                let (numer, denom) = split_denom(&digit);
+                terms.push(PartialFraction {
+                    numer,
+                    denom,
+                    factor: factor.clone(),
+                    power,
+                });
+            }
+        }
+        let (whole_num, whole_den) = split_denom(&whole);
+        PartialFractions {
+            whole: RationalFunction::new(whole_num, Polynomial::constant(whole_den)),
+            terms,
+        }
+    }
+}
+
+/// Splits a polynomial over the rationals into an integer polynomial over a positive
+/// constant denominator, with no common factor between the two.
+fn split_denom(q: &QPolynomial) -> (Polynomial, isize) {
+    let (primitive, factor) = q.to_parts();
+    (primitive * factor.numer(), factor.denom())
+}
@@ -0,0 +1,118 @@
+use crate::Polynomial;
+
+impl Polynomial {
+    /// Raises the polynomial to the power `e`, using binary exponentiation (so only about
+    /// `2 * log2(e)` polynomial multiplications are done). Anything to the zeroth power,
+    /// including zero, is the constant 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let linear = poly![1, 1]; // x + 1
+    /// assert_eq!(linear.pow(0), poly![1]);
+    /// assert_eq!(linear.pow(1), linear);
+    /// assert_eq!(linear.pow(5), poly![1, 5, 10, 10, 5, 1]);
+    /// assert_eq!(Polynomial::zero().pow(3), Polynomial::zero());
+    /// ```
+    pub fn pow(&self, mut e: u32) -> Self {
+        let mut base = self.clone();
+        let mut acc = Self::constant(1);
+        // walk through the bits of e from the bottom, squaring the base each time
+        // and multiplying it into the accumulator whenever the bit is set
+        while e > 0 {
+            if e & 1 == 1 {
+                acc *= &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// Raises the polynomial to the power `e` with coefficients taken modulo `m`,
+    /// reducing after every multiplication so the coefficients never grow past `m`.
+    /// The output's coefficients are all in the range `0..m`, as with `rem_euclid()`.
+    /// Panics if `m` isn't positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let linear = poly![1, 1]; // x + 1
+    /// assert_eq!(linear.pow_mod(5, 5), poly![1, 0, 0, 0, 0, 1]); // freshman's dream
+    /// assert_eq!(linear.pow_mod(4, 3), poly![1, 1, 0, 1, 1]);
+    /// assert_eq!(poly![-1, 2].pow_mod(2, 7), poly![1, 3, 4]);
+    ///
+    /// // the coefficients of (x + 1)^100 don't fit in an isize, but mod 7 that's no problem
+    /// assert_eq!(linear.pow_mod(100, 7).degree(), 100);
+    /// ```
+    pub fn pow_mod(&self, mut e: u32, m: isize) -> Self {
+        assert!(m > 0, "modulus {} is not positive", m);
+        let mut base = self.rem_euclid(m);
+        let mut acc = Self::constant(1).rem_euclid(m);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc.mul_mod(&base, m);
+            }
+            e >>= 1;
+            if e > 0 {
+                base = base.mul_mod(&base, m);
+            }
+        }
+        acc
+    }
+
+    /// Raises the polynomial to the power `e` modulo the polynomial `modulus`, reducing
+    /// after every multiplication so the degree stays below that of `modulus`. Returns
+    /// `None` if `modulus` is zero or has a leading coefficient other than 1 or -1, since
+    /// otherwise the reductions can't in general be done over the integers.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly};
+    ///
+    /// let x = poly![0, 1];
+    /// let modulus = poly![1, 0, 1]; // x^2 + 1, so x^2 = -1
+    /// assert_eq!(x.powmod_poly(2, &modulus), Some(poly![-1]));
+    /// assert_eq!(x.powmod_poly(3, &modulus), Some(poly![0, -1]));
+    /// assert_eq!(x.powmod_poly(1000, &modulus), Some(poly![1]));
+    /// assert_eq!(x.powmod_poly(2, &poly![1, 0, 2]), None);
+    /// ```
+    pub fn powmod_poly(&self, mut e: u32, modulus: &Self) -> Option<Self> {
+        match modulus.coeffs.last() {
+            Some(1) | Some(-1) => {}
+            _ => return None,
+        }
+        let mut base = self.rem_poly(modulus)?;
+        let mut acc = Self::constant(1).rem_poly(modulus)?;
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = (&acc * &base).rem_poly(modulus)?;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = (&base * &base).rem_poly(modulus)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Multiplies two polynomials whose coefficients are already reduced modulo `m`,
+    /// accumulating in `i128` so that the intermediate sums can't overflow.
+    pub(crate) fn mul_mod(&self, rhs: &Self, m: isize) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let m = m as i128;
+        let mut coeffs = vec![0i128; self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = (coeffs[i + j] + a as i128 * b as i128).rem_euclid(m);
+            }
+        }
+        Self::new(coeffs.into_iter().map(|c| c as isize).collect())
+    }
+}
@@ -0,0 +1,353 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::rational::{self, Rational};
+use crate::Polynomial;
+
+/// A polynomial with `Rational` coefficients, for the results that leave the integer
+/// polynomials: monic normalization, division by non-monic divisors, interpolation and the
+/// like. Coefficients are stored in increasing order with no trailing zeros, as with
+/// `Polynomial`.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, qpoly::QPolynomial, rational::Rational};
+///
+/// let a = QPolynomial::from(poly![1, 0, 1]); // x^2 + 1
+/// let b = QPolynomial::from(poly![1, 2]); // 2x + 1
+/// let (quot, rem) = a.div_rem(&b).unwrap();
+/// assert_eq!(quot.to_string(), "x/2 - 1/4");
+/// assert_eq!(rem.to_string(), "5/4");
+/// assert_eq!(&(&quot * &b) + &rem, a);
+///
+/// assert_eq!(b.monic().to_string(), "x + 1/2");
+/// assert_eq!(b.eval(Rational::new(1, 2)), Rational::from(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QPolynomial {
+    coeffs: Vec<Rational>,
+}
+
+impl QPolynomial {
+    /// Creates a polynomial with the given coefficients, stored in increasing order, with
+    /// any trailing (higher-degree) zeroes removed.
+    pub fn new(mut coeffs: Vec<Rational>) -> Self {
+        while coeffs.last().is_some_and(Rational::is_zero) {
+            coeffs.pop();
+        }
+        Self { coeffs }
+    }
+
+    /// Creates the zero polynomial.
+    pub fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    /// Creates a constant polynomial.
+    pub fn constant(c: Rational) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// Creates the polynomial `factor * poly`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, qpoly::QPolynomial, rational::Rational};
+    ///
+    /// let poly = QPolynomial::from_parts(&poly![0, 1, 1], Rational::new(1, 2));
+    /// assert_eq!(poly.to_string(), "x^2/2 + x/2");
+    /// ```
+    pub fn from_parts(poly: &Polynomial, factor: Rational) -> Self {
+        Self::new(
+            poly.coeffs
+                .iter()
+                .map(|&c| Rational::from(c) * factor)
+                .collect(),
+        )
+    }
+
+    /// Splits the polynomial into a primitive integer polynomial with positive leading
+    /// coefficient and the fraction it's multiplied by, so that `self` is
+    /// `from_parts(&primitive, factor)`. Zero splits into zero and 0. Panics if the common
+    /// denominator of the coefficients overflows an `isize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, qpoly::QPolynomial, rational::Rational};
+    ///
+    /// let poly = QPolynomial::new(vec![Rational::new(-1, 2), Rational::zero(), Rational::new(-3, 4)]);
+    /// assert_eq!(poly.to_parts(), (poly![2, 0, 3], Rational::new(-1, 4)));
+    /// ```
+    pub fn to_parts(&self) -> (Polynomial, Rational) {
+        self.checked_to_parts()
+            .expect("overflow in rational arithmetic")
+    }
+
+    /// Like `to_parts()`, but gives `None` rather than panicking if the common denominator
+    /// of the coefficients overflows an `isize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{qpoly::QPolynomial, rational::Rational};
+    ///
+    /// // the denominators are distinct primes, so their product doesn't fit
+    /// let primes = [1_000_000_007, 1_000_000_009, 1_000_000_021];
+    /// let poly = QPolynomial::new(primes.iter().map(|&p| Rational::new(1, p)).collect());
+    /// assert_eq!(poly.checked_to_parts(), None);
+    /// ```
+    pub fn checked_to_parts(&self) -> Option<(Polynomial, Rational)> {
+        let lead = match self.coeffs.last() {
+            Some(lead) => lead,
+            None => return Some((Polynomial::zero(), Rational::zero())),
+        };
+        // clear the denominators, then take out the content
+        let lcm = self.coeffs.iter().try_fold(1isize, |acc, c| {
+            (acc / Polynomial::int_gcd(acc, c.denom())).checked_mul(c.denom())
+        })?;
+        let cleared = self
+            .coeffs
+            .iter()
+            .map(|&c| Some(c.checked_mul(Rational::from(lcm))?.numer()));
+        let cleared = Polynomial::new(cleared.collect::<Option<_>>()?);
+        let content = cleared.content().checked_mul(lead.numer().signum())?;
+        Some((cleared.primitive_part(), Rational::new(content, lcm)))
+    }
+
+    /// Gives the polynomial with integer coefficients, if they all are whole numbers.
+    pub fn to_polynomial(&self) -> Option<Polynomial> {
+        let coeffs: Option<Vec<isize>> = self.coeffs.iter().map(Rational::to_integer).collect();
+        coeffs.map(Polynomial::new)
+    }
+
+    /// Returns the coefficients, in order of increasing degree.
+    pub fn coeffs(&self) -> &[Rational] {
+        &self.coeffs
+    }
+
+    /// Gives the coefficient of `x^n`, which is 0 past the degree.
+    pub fn coeff(&self, n: usize) -> Rational {
+        self.coeffs.get(n).copied().unwrap_or_default()
+    }
+
+    /// Gives the degree, which is -1 for the zero polynomial.
+    pub fn degree(&self) -> isize {
+        self.coeffs.len() as isize - 1
+    }
+
+    /// Checks whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Divides through by the leading coefficient; zero stays zero.
+    pub fn monic(&self) -> Self {
+        match self.coeffs.last() {
+            Some(&lead) => self * lead.recip(),
+            None => Self::zero(),
+        }
+    }
+
+    /// Plugs in a specific fraction `x`.
+    pub fn eval(&self, x: Rational) -> Rational {
+        let mut acc = Rational::zero();
+        for &c in self.coeffs.iter().rev() {
+            acc = acc * x + c;
+        }
+        acc
+    }
+
+    /// Gives the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let terms = self.coeffs.iter().enumerate().skip(1);
+        Self::new(
+            terms
+                .map(|(n, &c)| c * Rational::from(n as isize))
+                .collect(),
+        )
+    }
+
+    /// Raises the polynomial to the power `e` by repeated squaring.
+    pub fn pow(&self, mut e: u32) -> Self {
+        let mut acc = Self::constant(Rational::one());
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = &acc * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// Divides `self` by `divisor` with remainder, returning `Some((q, r))` where
+    /// `self = q * divisor + r` and `r.degree() < divisor.degree()`. Unlike
+    /// `Polynomial::div_rem()`, this always works, except when `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let lead = *divisor.coeffs.last()?;
+        if self.degree() < divisor.degree() {
+            return Some((Self::zero(), self.clone()));
+        }
+        let ddeg = divisor.coeffs.len() - 1;
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![Rational::zero(); rem.len() - ddeg];
+        // standard long division, from the top down
+        for n in (ddeg..rem.len()).rev() {
+            let q = rem[n] / lead;
+            quot[n - ddeg] = q;
+            for (i, &d) in divisor.coeffs.iter().enumerate() {
+                rem[n - ddeg + i] = rem[n - ddeg + i] - q * d;
+            }
+        }
+        rem.truncate(ddeg);
+        Some((Self::new(quot), Self::new(rem)))
+    }
+
+    /// Gives the monic greatest common divisor of two polynomials, or zero if both are
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, qpoly::QPolynomial};
+    ///
+    /// let a = QPolynomial::from(poly![-1, 0, 4]); // 4x^2 - 1
+    /// let b = QPolynomial::from(poly![1, 2]); // 2x + 1
+    /// assert_eq!(a.gcd(&b).to_string(), "x + 1/2");
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while let Some((_, rem)) = a.div_rem(&b) {
+            a = std::mem::replace(&mut b, rem);
+        }
+        a.monic()
+    }
+
+    /// Inverts `self` modulo `modulus` by the extended Euclidean algorithm, if the two are
+    /// coprime and `modulus` isn't constant; the inverse has lower degree than `modulus`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, qpoly::QPolynomial};
+    ///
+    /// let modulus = QPolynomial::from(poly![1, 0, 1]); // x^2 + 1
+    /// let inv = QPolynomial::from(poly![1, 1]).inv_mod(&modulus).unwrap();
+    /// assert_eq!(inv.to_string(), "-x/2 + 1/2");
+    /// assert_eq!(QPolynomial::from(poly![0, 0, 1]).inv_mod(&QPolynomial::from(poly![0, 1])), None);
+    /// ```
+    pub fn inv_mod(&self, modulus: &Self) -> Option<Self> {
+        if modulus.degree() < 1 {
+            return None;
+        }
+        let (mut r0, mut r1) = (modulus.clone(), self.div_rem(modulus)?.1);
+        let (mut s0, mut s1) = (Self::zero(), Self::constant(Rational::one()));
+        while let Some((quot, rem)) = r0.div_rem(&r1) {
+            let s = &s0 - &(&quot * &s1);
+            r0 = std::mem::replace(&mut r1, rem);
+            s0 = std::mem::replace(&mut s1, s);
+        }
+        // r0 is now the gcd, with s0 * self = r0 modulo the modulus
+        match r0.coeffs.as_slice() {
+            [c] => Some(&s0 * c.recip()),
+            _ => None,
+        }
+    }
+}
+
+impl From<&Polynomial> for QPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        Self {
+            coeffs: poly.coeffs.iter().map(|&c| Rational::from(c)).collect(),
+        }
+    }
+}
+
+impl From<Polynomial> for QPolynomial {
+    fn from(poly: Polynomial) -> Self {
+        Self::from(&poly)
+    }
+}
+
+impl std::fmt::Display for QPolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        rational::write_terms(f, self.coeffs.iter().copied().enumerate().rev())
+    }
+}
+
+impl Add for &QPolynomial {
+    type Output = QPolynomial;
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        QPolynomial::new((0..len).map(|n| self.coeff(n) + rhs.coeff(n)).collect())
+    }
+}
+
+impl Add for QPolynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for QPolynomial {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Sub for &QPolynomial {
+    type Output = QPolynomial;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs.clone())
+    }
+}
+
+impl Sub for QPolynomial {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for &QPolynomial {
+    type Output = QPolynomial;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return QPolynomial::zero();
+        }
+        let mut coeffs = vec![Rational::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j] + a * b;
+            }
+        }
+        QPolynomial::new(coeffs)
+    }
+}
+
+impl Mul for QPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<Rational> for &QPolynomial {
+    type Output = QPolynomial;
+    fn mul(self, rhs: Rational) -> Self::Output {
+        QPolynomial::new(self.coeffs.iter().map(|&c| c * rhs).collect())
+    }
+}
+
+impl Mul<Rational> for QPolynomial {
+    type Output = Self;
+    fn mul(self, rhs: Rational) -> Self::Output {
+        &self * rhs
+    }
+}
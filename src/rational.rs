@@ -0,0 +1,284 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::format::{write_sum, Term, SPACED};
+
+/// An exact fraction of two `isize`s, always kept in lowest terms with a positive
+/// denominator, for the results that leave the integers.
+///
+/// # Examples
+/// ```
+/// use polynomint::rational::Rational;
+///
+/// let half = Rational::new(2, 4);
+/// assert_eq!(half, Rational::new(-1, -2));
+/// assert_eq!((half.numer(), half.denom()), (1, 2));
+/// assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+/// assert_eq!((half / Rational::from(-3)).to_string(), "-1/6");
+/// assert_eq!((half * Rational::from(4)).to_integer(), Some(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: isize,
+    den: isize,
+}
+
+impl Rational {
+    /// Creates the fraction `num / den` in lowest terms. Panics if `den` is zero.
+    pub fn new(num: isize, den: isize) -> Self {
+        assert!(den != 0, "rational with zero denominator");
+        Self::reduced(num as i128, den as i128)
+    }
+
+    /// Creates the fraction 0.
+    pub fn zero() -> Self {
+        Self { num: 0, den: 1 }
+    }
+
+    /// Creates the fraction 1.
+    pub fn one() -> Self {
+        Self { num: 1, den: 1 }
+    }
+
+    /// Brings a fraction worked out in `i128` back to lowest terms, panicking if it
+    /// doesn't fit in `isize` even then.
+    pub(crate) fn reduced(num: i128, den: i128) -> Self {
+        Self::checked_reduced(num, den).expect("overflow in rational arithmetic")
+    }
+
+    /// Brings a fraction worked out in `i128` back to lowest terms, or gives `None` if it
+    /// doesn't fit in `isize` even then.
+    fn checked_reduced(mut num: i128, mut den: i128) -> Option<Self> {
+        let (mut a, mut b) = (num.abs(), den.abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        if den < 0 {
+            a = -a;
+        }
+        if a != 0 {
+            num /= a;
+            den /= a;
+        }
+        Some(Self {
+            num: isize::try_from(num).ok()?,
+            den: isize::try_from(den).ok()?,
+        })
+    }
+
+    /// Gives the numerator, which carries the sign.
+    pub fn numer(&self) -> isize {
+        self.num
+    }
+
+    /// Gives the denominator, which is always positive.
+    pub fn denom(&self) -> isize {
+        self.den
+    }
+
+    /// Checks whether this is 0.
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// Checks whether this is a whole number.
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// Gives the fraction as an `isize`, if it's a whole number.
+    pub fn to_integer(&self) -> Option<isize> {
+        if self.is_integer() {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    /// Gives the absolute value. Panics if the numerator is `isize::MIN`.
+    pub fn abs(&self) -> Self {
+        if self.num < 0 {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    /// Gives `1 / self`. Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::one() / *self
+    }
+
+    /// Raises the fraction to the power `e`. Panics if the result overflows.
+    pub fn pow(&self, e: u32) -> Self {
+        self.checked_pow(e)
+            .expect("overflow in rational arithmetic")
+    }
+
+    /// Adds two fractions, giving `None` if the result overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (a, b, c, d) = (
+            self.num as i128,
+            self.den as i128,
+            rhs.num as i128,
+            rhs.den as i128,
+        );
+        Self::checked_reduced(a * d + c * b, b * d)
+    }
+
+    /// Subtracts two fractions, giving `None` if the result overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// Negates the fraction, giving `None` if the numerator is `isize::MIN`.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// Multiplies two fractions, giving `None` if the result overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::rational::Rational;
+    ///
+    /// let big = Rational::new(isize::MAX, 2);
+    /// assert_eq!(big.checked_mul(Rational::new(2, 3)), Some(Rational::new(isize::MAX, 3)));
+    /// assert_eq!(big.checked_mul(Rational::from(3)), None);
+    /// ```
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::checked_reduced(
+            self.num as i128 * rhs.num as i128,
+            self.den as i128 * rhs.den as i128,
+        )
+    }
+
+    /// Divides two fractions, giving `None` if `rhs` is zero or the result overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Self::checked_reduced(
+            self.num as i128 * rhs.den as i128,
+            self.den as i128 * rhs.num as i128,
+        )
+    }
+
+    /// Raises the fraction to the power `e`, giving `None` if the result overflows; since
+    /// the fraction is in lowest terms, so is its power.
+    pub fn checked_pow(&self, e: u32) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_pow(e)?,
+            den: self.den.checked_pow(e)?,
+        })
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(n: isize) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // denominators are positive, so cross-multiplying keeps the order
+        (self.num as i128 * other.den as i128).cmp(&(other.num as i128 * self.den as i128))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Writes out `(power, coefficient)` terms in the order given, in the style of
+/// `Polynomial`'s `Display`, with a fractional coefficient's denominator written after
+/// the power of `x` (like `3x^2/4`). Writes nothing at all if every coefficient is zero.
+pub(crate) fn write_terms<I>(f: &mut fmt::Formatter<'_>, terms: I) -> fmt::Result
+where
+    I: Iterator<Item = (usize, Rational)>,
+{
+    let terms = terms
+        .filter(|(_, c)| !c.is_zero())
+        .map(|(n, c)| Term::new(c.num, n == 0, (n, c.den)));
+    // a numerator of 1 is left out just like a coefficient of 1
+    write_sum(f, SPACED, terms, |f, numer, (n, den)| {
+        if let Some(abs) = numer {
+            write!(f, "{}", abs)?;
+        }
+        if n > 0 {
+            write!(f, "x")?;
+        }
+        if n > 1 {
+            write!(f, "^{}", n)?;
+        }
+        if den != 1 {
+            write!(f, "/{}", den)?;
+        }
+        Ok(())
+    })
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow in rational arithmetic")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("overflow in rational arithmetic")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("overflow in rational arithmetic")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// Divides two fractions. Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "rational division by zero");
+        self.checked_div(rhs)
+            .expect("overflow in rational arithmetic")
+    }
+}
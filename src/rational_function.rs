@@ -0,0 +1,240 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::rational::Rational;
+use crate::Polynomial;
+
+/// A quotient of two integer polynomials, always kept in lowest terms: the numerator and
+/// denominator have no common factor (not even a constant one), and the denominator has
+/// positive leading coefficient. That makes the representation unique, so two rational
+/// functions are equal exactly when their numerators and denominators are.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, rational_function::RationalFunction};
+///
+/// // (2x^2 - 2)/(-4x - 4) = (1 - x)/2
+/// let f = RationalFunction::new(poly![-2, 0, 2], poly![-4, -4]);
+/// assert_eq!(f.numer(), &poly![1, -1]);
+/// assert_eq!(f.denom(), &poly![2]);
+/// assert_eq!(f.to_string(), "(-x + 1)/2");
+///
+/// let g = RationalFunction::new(poly![1], poly![-1, 1]); // 1/(x - 1)
+/// assert_eq!((&g + &g).to_string(), "2/(x - 1)");
+/// assert_eq!(&g * &RationalFunction::from(poly![-1, 1]), RationalFunction::from(poly![1]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RationalFunction {
+    num: Polynomial,
+    den: Polynomial,
+}
+
+impl RationalFunction {
+    /// Creates the rational function `num / den`, brought to lowest terms. Panics if `den`
+    /// is zero.
+    pub fn new(num: Polynomial, den: Polynomial) -> Self {
+        assert!(!den.is_zero(), "rational function with zero denominator");
+        // the gcd includes the gcd of the contents, so constant factors cancel too
+        let gcd = num.gcd(&den);
+        let mut num = exact_div(&num, &gcd);
+        let mut den = exact_div(&den, &gcd);
+        if den.coeffs.last().is_some_and(|&lead| lead < 0) {
+            num = -num;
+            den = -den;
+        }
+        Self { num, den }
+    }
+
+    /// Creates the zero rational function, `0/1`.
+    pub fn zero() -> Self {
+        Self::from(Polynomial::zero())
+    }
+
+    /// Gives the numerator.
+    pub fn numer(&self) -> &Polynomial {
+        &self.num
+    }
+
+    /// Gives the denominator, which always has positive leading coefficient.
+    pub fn denom(&self) -> &Polynomial {
+        &self.den
+    }
+
+    /// Checks whether this is the zero rational function.
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    /// Gives the rational function as a polynomial, if its denominator is 1.
+    pub fn to_polynomial(&self) -> Option<Polynomial> {
+        if self.den == Polynomial::constant(1) {
+            Some(self.num.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Gives `1 / self`. Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.den.clone(), self.num.clone())
+    }
+
+    /// Plugs in a specific `isize` value `x`, giving an exact fraction, or `None` if `x`
+    /// is a pole (a root of the denominator).
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational::Rational, rational_function::RationalFunction};
+    ///
+    /// let f = RationalFunction::new(poly![1, 1], poly![0, 2]); // (x + 1)/2x
+    /// assert_eq!(f.eval(3), Some(Rational::new(2, 3)));
+    /// assert_eq!(f.eval(0), None);
+    /// ```
+    pub fn eval(&self, x: isize) -> Option<Rational> {
+        match self.den.eval(x) {
+            0 => None,
+            den => Some(Rational::new(self.num.eval(x), den)),
+        }
+    }
+
+    /// Gives the derivative, by the quotient rule.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational_function::RationalFunction};
+    ///
+    /// let f = RationalFunction::new(poly![1], poly![0, 1]); // 1/x
+    /// assert_eq!(f.derivative(), RationalFunction::new(poly![-1], poly![0, 0, 1]));
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let num = &self.num.derivative() * &self.den - &self.num * &self.den.derivative();
+        Self::new(num, &self.den * &self.den)
+    }
+
+    /// Raises the rational function to the power `e` by repeated squaring; since the
+    /// numerator and denominator are coprime, so are their powers.
+    pub fn pow(&self, e: u32) -> Self {
+        Self {
+            num: self.num.pow(e),
+            den: self.den.pow(e),
+        }
+    }
+}
+
+/// Divides `a` by a factor `d` that's known to go into it exactly.
+fn exact_div(a: &Polynomial, d: &Polynomial) -> Polynomial {
+    let (quot, _) = a.div_rem(d).expect("division by a polynomial gcd is exact");
+    quot
+}
+
+impl From<Polynomial> for RationalFunction {
+    fn from(poly: Polynomial) -> Self {
+        Self {
+            num: poly,
+            den: Polynomial::constant(1),
+        }
+    }
+}
+
+impl From<&Polynomial> for RationalFunction {
+    fn from(poly: &Polynomial) -> Self {
+        Self::from(poly.clone())
+    }
+}
+
+impl std::fmt::Display for RationalFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // anything with more than one term needs parentheses around it
+        let terms = |p: &Polynomial| p.coeffs.iter().filter(|&&c| c != 0).count();
+        if self.den == Polynomial::constant(1) {
+            return write!(f, "{}", self.num);
+        }
+        if terms(&self.num) > 1 {
+            write!(f, "({})", self.num)?;
+        } else {
+            write!(f, "{}", self.num)?;
+        }
+        if terms(&self.den) > 1 {
+            write!(f, "/({})", self.den)
+        } else {
+            write!(f, "/{}", self.den)
+        }
+    }
+}
+
+impl Add for &RationalFunction {
+    type Output = RationalFunction;
+    fn add(self, rhs: Self) -> Self::Output {
+        let num = &self.num * &rhs.den + &rhs.num * &self.den;
+        RationalFunction::new(num, &self.den * &rhs.den)
+    }
+}
+
+impl Add for RationalFunction {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for RationalFunction {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for &RationalFunction {
+    type Output = RationalFunction;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs.clone())
+    }
+}
+
+impl Sub for RationalFunction {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for &RationalFunction {
+    type Output = RationalFunction;
+    fn mul(self, rhs: Self) -> Self::Output {
+        RationalFunction::new(&self.num * &rhs.num, &self.den * &rhs.den)
+    }
+}
+
+impl Mul for RationalFunction {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Div for &RationalFunction {
+    type Output = RationalFunction;
+
+    /// Divides two rational functions. Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, rational_function::RationalFunction};
+    ///
+    /// let a = RationalFunction::from(poly![-1, 0, 1]); // x^2 - 1
+    /// let b = RationalFunction::new(poly![1, 1], poly![0, 1]); // (x + 1)/x
+    /// assert_eq!((&a / &b).to_string(), "x^2 - x");
+    /// ```
+    fn div(self, rhs: Self) -> Self::Output {
+        RationalFunction::new(&self.num * &rhs.den, &self.den * &rhs.num)
+    }
+}
+
+impl Div for RationalFunction {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
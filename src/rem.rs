@@ -17,7 +17,7 @@ impl Rem<isize> for Polynomial {
     }
 }
 
-impl<'a> Rem<isize> for &'a Polynomial {
+impl Rem<isize> for &Polynomial {
     type Output = Polynomial;
     fn rem(self, rhs: isize) -> Self::Output {
         if self.is_zero() {
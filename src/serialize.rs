@@ -0,0 +1,110 @@
+//! `serde` support for `Polynomial`, enabled with the `serde` feature.
+//!
+//! By default a polynomial is serialized compactly, as its array of coefficients in
+//! order of ascending degree. When deserializing from a human-readable format like JSON,
+//! either that array or a string like `"x^2 - 2x + 1"` is accepted; the `string` module
+//! can be used with `#[serde(with = "...")]` to always write the string form instead.
+//!
+//! Deserializing rejects coefficient arrays ending in a zero, since no `Polynomial`
+//! is ever stored that way.
+//!
+//! # Examples
+//! ```
+//! use polynomint::{Polynomial, poly};
+//! use serde::{Deserialize, Serialize};
+//!
+//! let poly = poly![1, -2, 1];
+//! assert_eq!(serde_json::to_string(&poly).unwrap(), "[1,-2,1]");
+//! assert_eq!(serde_json::from_str::<Polynomial>("[1,-2,1]").unwrap(), poly);
+//! assert_eq!(serde_json::from_str::<Polynomial>("\"x^2 - 2x + 1\"").unwrap(), poly);
+//! assert!(serde_json::from_str::<Polynomial>("[1,-2,1,0]").is_err());
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "polynomint::serialize::string")]
+//!     generator: Polynomial,
+//! }
+//!
+//! let config = Config { generator: poly };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"generator":"x^2 - 2x + 1"}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::Polynomial;
+
+/// The most coefficients `visit_seq()` reserves room for up front, like serde's own
+/// collections do: the length hint comes from the input, so a few bytes claiming a
+/// billion elements mustn't allocate gigabytes before any of them turn up.
+const MAX_PREALLOCATED: usize = 1024 * 1024 / std::mem::size_of::<isize>();
+
+impl Serialize for Polynomial {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.coeffs.len()))?;
+        for coeff in self.coeffs.iter() {
+            seq.serialize_element(coeff)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Polynomial {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PolynomialVisitor)
+        } else {
+            deserializer.deserialize_seq(PolynomialVisitor)
+        }
+    }
+}
+
+struct PolynomialVisitor;
+
+impl<'de> Visitor<'de> for PolynomialVisitor {
+    type Value = Polynomial;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an array of coefficients or a polynomial string")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED);
+        let mut coeffs = Vec::with_capacity(capacity);
+        while let Some(coeff) = seq.next_element::<isize>()? {
+            coeffs.push(coeff);
+        }
+        if coeffs.last() == Some(&0) {
+            return Err(de::Error::custom("coefficient array has a trailing zero"));
+        }
+        Ok(Polynomial { coeffs })
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Serializes a polynomial as the string `Display` writes, and deserializes it with the
+/// crate's parser; for use with `#[serde(with = "polynomint::serialize::string")]`.
+pub mod string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Polynomial;
+
+    /// Serializes `poly` as a string.
+    pub fn serialize<S: Serializer>(poly: &Polynomial, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(poly)
+    }
+
+    /// Deserializes a polynomial from a string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Polynomial, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
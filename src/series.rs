@@ -0,0 +1,437 @@
+use std::convert::TryFrom;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::rational::{self, Rational};
+use crate::Polynomial;
+
+/// A formal power series known up to a precision cap: the coefficients of `x^0` through
+/// `x^(precision - 1)`, with everything from `x^precision` on unknown. Coefficients are
+/// `Rational`s, since `exp()`, `log()` and roots rarely stay integral; `to_polynomial()`
+/// gets the integer coefficients back when they are. The methods that give an `Option`
+/// also give `None` if a coefficient overflows an `isize`, which happens quickly for
+/// `exp()`: its terms have denominators `n!`. The arithmetic operators panic instead.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, series::PowerSeries};
+///
+/// // the generating function of the Fibonacci numbers, 1/(1 - x - x^2)
+/// let denom = PowerSeries::from_polynomial(&poly![1, -1, -1], 10);
+/// let fib = denom.inverse().unwrap();
+/// assert_eq!(fib.to_polynomial(), Some(poly![1, 1, 2, 3, 5, 8, 13, 21, 34, 55]));
+/// assert_eq!(fib.precision(), 10);
+///
+/// let short = PowerSeries::from_polynomial(&poly![1, 1], 4).inverse().unwrap();
+/// assert_eq!(short.to_string(), "1 - x + x^2 - x^3 + O(x^4)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerSeries {
+    coeffs: Vec<Rational>,
+}
+
+impl PowerSeries {
+    /// Creates a power series whose known coefficients are `coeffs`, in increasing
+    /// order, so that its precision is `coeffs.len()`.
+    pub fn new(coeffs: Vec<Rational>) -> Self {
+        Self { coeffs }
+    }
+
+    /// Creates the zero series up to the given precision.
+    pub fn zero(precision: usize) -> Self {
+        Self {
+            coeffs: vec![Rational::zero(); precision],
+        }
+    }
+
+    /// Creates the series of a polynomial up to the given precision, cutting off any
+    /// terms of degree `precision` or more.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// let series = PowerSeries::from_polynomial(&poly![1, 2, 3, 4], 3);
+    /// assert_eq!(series.to_string(), "1 + 2x + 3x^2 + O(x^3)");
+    /// ```
+    pub fn from_polynomial(poly: &Polynomial, precision: usize) -> Self {
+        Self {
+            coeffs: (0..precision)
+                .map(|n| Rational::from(poly.coeff(n)))
+                .collect(),
+        }
+    }
+
+    /// Gives the number of coefficients known; the series is exact modulo `x^precision`.
+    pub fn precision(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Returns the known coefficients, in increasing order.
+    pub fn coeffs(&self) -> &[Rational] {
+        &self.coeffs
+    }
+
+    /// Gives the coefficient of `x^n`. Panics unless `n` is below the precision.
+    pub fn coeff(&self, n: usize) -> Rational {
+        self.coeffs[n]
+    }
+
+    /// Gives the known part of the series as an integer polynomial, if every coefficient
+    /// is a whole number.
+    pub fn to_polynomial(&self) -> Option<Polynomial> {
+        let coeffs: Option<Vec<isize>> = self.coeffs.iter().map(Rational::to_integer).collect();
+        coeffs.map(Polynomial::new)
+    }
+
+    /// Lowers the precision to `precision`, dropping the coefficients from there on; a
+    /// higher `precision` leaves the series as it is, since those terms aren't known.
+    pub fn truncate(&self, precision: usize) -> Self {
+        let precision = precision.min(self.precision());
+        Self {
+            coeffs: self.coeffs[..precision].to_vec(),
+        }
+    }
+
+    /// Gives the multiplicative inverse, which exists when the constant term is nonzero
+    /// (in particular, when it's ±1, the inverse of an integer series stays integral).
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// // 1/(1 - 2x) = 1 + 2x + 4x^2 + ...
+    /// let series = PowerSeries::from_polynomial(&poly![1, -2], 5).inverse().unwrap();
+    /// assert_eq!(series.to_polynomial(), Some(poly![1, 2, 4, 8, 16]));
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![0, 1], 5).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let a0 = *self.coeffs.first()?;
+        if a0.is_zero() {
+            return None;
+        }
+        // comparing coefficients in self * inv = 1 gives inv[n] one at a time
+        let mut inv = Vec::with_capacity(self.precision());
+        inv.push(Rational::one().checked_div(a0)?);
+        for n in 1..self.precision() {
+            let sum = (1..=n).try_fold(Rational::zero(), |acc, k| {
+                acc.checked_add(self.coeffs[k].checked_mul(inv[n - k])?)
+            })?;
+            inv.push(sum.checked_neg()?.checked_div(a0)?);
+        }
+        Some(Self { coeffs: inv })
+    }
+
+    /// Substitutes the series `inner` for `x`, giving `self(inner(x))`, which needs
+    /// `inner` to have no constant term. The result is known up to the lower of the two
+    /// precisions.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// // 1/(1 - y) with y = x + x^2 is 1/(1 - x - x^2) again
+    /// let geometric = PowerSeries::from_polynomial(&poly![1, -1], 8).inverse().unwrap();
+    /// let inner = PowerSeries::from_polynomial(&poly![0, 1, 1], 8);
+    /// let fib = geometric.compose(&inner).unwrap();
+    /// assert_eq!(fib.to_polynomial(), Some(poly![1, 1, 2, 3, 5, 8, 13, 21]));
+    /// ```
+    pub fn compose(&self, inner: &Self) -> Option<Self> {
+        if inner.coeffs.first().is_some_and(|c| !c.is_zero()) {
+            return None;
+        }
+        let precision = self.precision().min(inner.precision());
+        let inner = inner.truncate(precision);
+        // Horner's rule, where every multiplication by inner raises the lowest power, so
+        // only the first `precision` coefficients of self can matter
+        let mut acc = Self::zero(precision);
+        for &c in self.coeffs[..precision].iter().rev() {
+            acc = acc.checked_mul(&inner)?;
+            if let Some(constant) = acc.coeffs.first_mut() {
+                *constant = constant.checked_add(c)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Gives the exponential `exp(self)`, which needs the constant term to be zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// let exp = PowerSeries::from_polynomial(&poly![0, 1], 5).exp().unwrap();
+    /// assert_eq!(exp.to_string(), "1 + x + x^2/2 + x^3/6 + x^4/24 + O(x^5)");
+    /// assert_eq!(exp.log().unwrap(), PowerSeries::from_polynomial(&poly![0, 1], 5));
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![1, 1], 5).exp(), None);
+    ///
+    /// // 20! fits in an isize, but 21! doesn't
+    /// assert!(PowerSeries::from_polynomial(&poly![0, 1], 21).exp().is_some());
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![0, 1], 22).exp(), None);
+    /// ```
+    pub fn exp(&self) -> Option<Self> {
+        if self.coeffs.first().is_some_and(|c| !c.is_zero()) {
+            return None;
+        }
+        if self.coeffs.is_empty() {
+            return Some(self.clone());
+        }
+        // e = exp(self) has e' = self' * e, which gives e[n] from the earlier coefficients
+        let mut e = Vec::with_capacity(self.precision());
+        e.push(Rational::one());
+        for n in 1..self.precision() {
+            let sum = (1..=n).try_fold(Rational::zero(), |acc, k| {
+                let term = Rational::from(k as isize).checked_mul(self.coeffs[k])?;
+                acc.checked_add(term.checked_mul(e[n - k])?)
+            })?;
+            e.push(sum.checked_div(Rational::from(n as isize))?);
+        }
+        Some(Self { coeffs: e })
+    }
+
+    /// Gives the logarithm `log(self)`, which needs the constant term to be 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// // log(1/(1 - x)) = x + x^2/2 + x^3/3 + ...
+    /// let series = PowerSeries::from_polynomial(&poly![1, -1], 5).inverse().unwrap();
+    /// assert_eq!(series.log().unwrap().to_string(), "x + x^2/2 + x^3/3 + x^4/4 + O(x^5)");
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![2, 1], 5).log(), None);
+    /// ```
+    pub fn log(&self) -> Option<Self> {
+        if self.coeffs.is_empty() {
+            return Some(self.clone());
+        }
+        if self.coeffs[0] != Rational::one() {
+            return None;
+        }
+        // l = log(self) has self * l' = self', which gives l[n] from the earlier coefficients
+        let mut l = Vec::with_capacity(self.precision());
+        l.push(Rational::zero());
+        for n in 1..self.precision() {
+            let sum = (1..n).try_fold(Rational::zero(), |acc, k| {
+                let term = Rational::from(k as isize).checked_mul(l[k])?;
+                acc.checked_add(term.checked_mul(self.coeffs[n - k])?)
+            })?;
+            l.push(self.coeffs[n].checked_sub(sum.checked_div(Rational::from(n as isize))?)?);
+        }
+        Some(Self { coeffs: l })
+    }
+
+    /// Gives an `n`th root of the series, which needs the constant term to be the `n`th
+    /// power of a fraction; for even `n`, the root with positive constant term is given.
+    /// Gives `None` for `n = 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// // sqrt(1 - 4x) = 1 - 2x - 2x^2 - 4x^3 - 10x^4 - ..., the Catalan numbers times -2
+    /// let series = PowerSeries::from_polynomial(&poly![1, -4], 6).nth_root(2).unwrap();
+    /// assert_eq!(series.to_polynomial(), Some(poly![1, -2, -2, -4, -10, -28]));
+    ///
+    /// let cube = PowerSeries::from_polynomial(&poly![-1, 1], 4).pow(3).unwrap();
+    /// assert_eq!(cube.nth_root(3), Some(PowerSeries::from_polynomial(&poly![-1, 1], 4)));
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![2, 1], 4).nth_root(2), None);
+    ///
+    /// // constant terms at the ends of the isize range work too
+    /// let min = PowerSeries::from_polynomial(&poly![isize::MIN], 1);
+    /// assert_eq!(min.nth_root(3), Some(PowerSeries::from_polynomial(&poly![-(1 << 21)], 1)));
+    /// assert_eq!(PowerSeries::from_polynomial(&poly![isize::MAX], 1).nth_root(2), None);
+    /// ```
+    pub fn nth_root(&self, n: u32) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+        if self.coeffs.is_empty() {
+            return Some(self.clone());
+        }
+        let a0 = self.coeffs[0];
+        let r0 = Rational::new(int_root(a0.numer(), n)?, int_root(a0.denom(), n)?);
+        // r = self^(1/n) has n * self * r' = self' * r, which gives r[m] from the earlier
+        // coefficients; written out, m * a0 * r[m] = sum of (k/n - (m - k)) * a[k] * r[m - k]
+        let alpha = Rational::new(1, n as isize);
+        let mut r = Vec::with_capacity(self.precision());
+        r.push(r0);
+        for m in 1..self.precision() {
+            let sum = (1..=m).try_fold(Rational::zero(), |acc, k| {
+                let weight = alpha
+                    .checked_mul(Rational::from(k as isize))?
+                    .checked_sub(Rational::from((m - k) as isize))?;
+                let term = weight.checked_mul(self.coeffs[k])?;
+                acc.checked_add(term.checked_mul(r[m - k])?)
+            })?;
+            r.push(sum.checked_div(Rational::from(m as isize).checked_mul(a0)?)?);
+        }
+        Some(Self { coeffs: r })
+    }
+
+    /// Raises the series to the power `e` by repeated squaring.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// let series = PowerSeries::from_polynomial(&poly![1, 1], 4);
+    /// assert_eq!(series.pow(3).unwrap().to_polynomial(), Some(poly![1, 3, 3, 1]));
+    /// let big = PowerSeries::from_polynomial(&poly![1, isize::MAX], 4);
+    /// assert_eq!(big.pow(2), None);
+    /// ```
+    pub fn pow(&self, mut e: u32) -> Option<Self> {
+        let mut acc = Self::from_polynomial(&Polynomial::constant(1), self.precision());
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = acc.checked_mul(&base)?;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Multiplies two series like `*` does, giving `None` if a coefficient overflows.
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let precision = self.precision().min(rhs.precision());
+        let mut coeffs = vec![Rational::zero(); precision];
+        for (i, &a) in self.coeffs[..precision].iter().enumerate() {
+            if a.is_zero() {
+                continue;
+            }
+            for (j, &b) in rhs.coeffs[..precision - i].iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].checked_add(a.checked_mul(b)?)?;
+            }
+        }
+        Some(Self { coeffs })
+    }
+
+    /// Gives the derivative, which is known to one less term than the series.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::{Polynomial, poly, series::PowerSeries};
+    ///
+    /// let series = PowerSeries::from_polynomial(&poly![1, 1, 1, 1], 4);
+    /// assert_eq!(series.derivative().to_string(), "1 + 2x + 3x^2 + O(x^3)");
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let terms = self.coeffs.iter().enumerate().skip(1);
+        Self {
+            coeffs: terms
+                .map(|(n, &c)| c * Rational::from(n as isize))
+                .collect(),
+        }
+    }
+}
+
+/// Gives the `n`th root of `x` if it's a whole number, taking the negative root of a
+/// negative `x` when `n` is odd.
+fn int_root(x: isize, n: u32) -> Option<isize> {
+    if x < 0 && n % 2 == 0 {
+        return None;
+    }
+    // work with the magnitude as a u64 so that isize::MIN needs no special case
+    let abs = x.unsigned_abs() as u64;
+    // the floating point root is close enough that one of its neighbours is exact
+    let guess = (abs as f64).powf(1.0 / n as f64).round() as u64;
+    let root = (guess.saturating_sub(1)..=guess.saturating_add(1))
+        .find(|&r| r.checked_pow(n) == Some(abs))? as i128;
+    isize::try_from(if x < 0 { -root } else { root }).ok()
+}
+
+impl std::fmt::Display for PowerSeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        rational::write_terms(f, self.coeffs.iter().copied().enumerate())?;
+        let sep = if self.coeffs.iter().all(Rational::is_zero) {
+            ""
+        } else {
+            " + "
+        };
+        match self.precision() {
+            0 => write!(f, "{}O(1)", sep),
+            1 => write!(f, "{}O(x)", sep),
+            n => write!(f, "{}O(x^{})", sep, n),
+        }
+    }
+}
+
+impl Add for &PowerSeries {
+    type Output = PowerSeries;
+    fn add(self, rhs: Self) -> Self::Output {
+        let pairs = self.coeffs.iter().zip(rhs.coeffs.iter());
+        PowerSeries {
+            coeffs: pairs.map(|(&a, &b)| a + b).collect(),
+        }
+    }
+}
+
+impl Add for PowerSeries {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for PowerSeries {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Sub for &PowerSeries {
+    type Output = PowerSeries;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs.clone())
+    }
+}
+
+impl Sub for PowerSeries {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul for &PowerSeries {
+    type Output = PowerSeries;
+
+    /// Multiplies two series, keeping only the terms below the lower of the two
+    /// precisions, since everything past that depends on unknown coefficients.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("overflow in power series multiplication")
+    }
+}
+
+impl Mul for PowerSeries {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<isize> for &PowerSeries {
+    type Output = PowerSeries;
+    fn mul(self, rhs: isize) -> Self::Output {
+        PowerSeries {
+            coeffs: self
+                .coeffs
+                .iter()
+                .map(|&c| c * Rational::from(rhs))
+                .collect(),
+        }
+    }
+}
+
+impl Mul<isize> for PowerSeries {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self::Output {
+        &self * rhs
+    }
+}
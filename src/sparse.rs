@@ -0,0 +1,349 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::convert::TryFrom;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::format::PolyFormatter;
+use crate::Polynomial;
+
+/// A polynomial stored as its nonzero terms only, as `(power, coefficient)` pairs in order of
+/// ascending power, for polynomials like `x^1000000 + 1` whose dense `Polynomial` would be
+/// mostly zeroes.
+///
+/// # Examples
+/// ```
+/// use polynomint::{Polynomial, poly, sparse::SparsePolynomial};
+///
+/// let trinomial = SparsePolynomial::new(vec![(1_000_000, 1), (1, -1), (0, 1)]);
+/// assert_eq!(trinomial.to_string(), "x^1000000 - x + 1");
+/// assert_eq!(trinomial.degree(), 1_000_000);
+/// assert_eq!(trinomial.eval(1), 1);
+///
+/// let dense = poly![1, 0, 0, 2];
+/// assert_eq!(SparsePolynomial::from(&dense).terms(), &[(0, 1), (3, 2)]);
+/// assert_eq!(Polynomial::from(SparsePolynomial::from(&dense)), dense);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SparsePolynomial {
+    terms: Vec<(usize, isize)>,
+}
+
+impl SparsePolynomial {
+    /// Creates a sparse polynomial from `(power, coefficient)` terms given in any order,
+    /// adding together the coefficients of repeated powers and leaving out zeroes.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let poly = SparsePolynomial::new(vec![(5, 2), (0, 3), (5, -2), (2, 0), (7, 1)]);
+    /// assert_eq!(poly.terms(), &[(0, 3), (7, 1)]);
+    /// ```
+    pub fn new(mut terms: Vec<(usize, isize)>) -> Self {
+        terms.sort_unstable_by_key(|&(n, _)| n);
+        let mut combined: Vec<(usize, isize)> = Vec::with_capacity(terms.len());
+        for (n, c) in terms {
+            match combined.last_mut() {
+                Some(last) if last.0 == n => last.1 += c,
+                _ => combined.push((n, c)),
+            }
+        }
+        combined.retain(|&(_, c)| c != 0);
+        Self { terms: combined }
+    }
+
+    /// Creates the zero polynomial, which has no terms.
+    pub fn zero() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    /// Returns the nonzero terms as `(power, coefficient)` pairs, in order of ascending power.
+    pub fn terms(&self) -> &[(usize, isize)] {
+        &self.terms
+    }
+
+    /// Gives the highest power with a nonzero coefficient, or -1 for the zero polynomial,
+    /// as with `Polynomial::degree()`.
+    pub fn degree(&self) -> isize {
+        self.terms.last().map_or(-1, |&(n, _)| n as isize)
+    }
+
+    /// Checks whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Gives the coefficient of `x^n`, which is 0 if there's no such term.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let poly = SparsePolynomial::new(vec![(0, 1), (100, -3)]);
+    /// assert_eq!(poly.coeff(100), -3);
+    /// assert_eq!(poly.coeff(50), 0);
+    /// ```
+    pub fn coeff(&self, n: usize) -> isize {
+        match self.terms.binary_search_by_key(&n, |&(m, _)| m) {
+            Ok(i) => self.terms[i].1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Plugs in a specific `isize` value `x`, raising `x` to each power by repeated
+    /// squaring so that the work depends on the number of terms, not the degree.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let poly = SparsePolynomial::new(vec![(0, 1), (62, 1)]); // x^62 + 1
+    /// assert_eq!(poly.eval(2), (1 << 62) + 1);
+    /// assert_eq!(poly.eval(-1), 2);
+    /// ```
+    pub fn eval(&self, x: isize) -> isize {
+        let mut acc = 0;
+        // x^n for the power n of the previous term, so each term only needs the power of
+        // x making up the gap since then
+        let mut power = 1;
+        let mut prev = 0;
+        for &(n, c) in self.terms.iter() {
+            power *= int_pow(x, n - prev);
+            prev = n;
+            acc += c * power;
+        }
+        acc
+    }
+
+    /// Gives the derivative of the polynomial.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let poly = SparsePolynomial::new(vec![(0, 7), (1, 2), (1000, 3)]);
+    /// assert_eq!(poly.derivative(), SparsePolynomial::new(vec![(0, 2), (999, 3000)]));
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let terms = self.terms.iter().filter(|&&(n, _)| n > 0);
+        Self {
+            terms: terms.map(|&(n, c)| (n - 1, c * n as isize)).collect(),
+        }
+    }
+
+    /// Raises the polynomial to the power `e` by repeated squaring.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let poly = SparsePolynomial::new(vec![(0, 1), (1000, 1)]); // x^1000 + 1
+    /// assert_eq!(
+    ///     poly.pow(3),
+    ///     SparsePolynomial::new(vec![(0, 1), (1000, 3), (2000, 3), (3000, 1)])
+    /// );
+    /// assert_eq!(poly.pow(0), SparsePolynomial::new(vec![(0, 1)]));
+    /// ```
+    pub fn pow(&self, mut e: u32) -> Self {
+        let mut acc = Self {
+            terms: vec![(0, 1)],
+        };
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = &acc * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// Merges the terms of `self` with those of `sign` times `rhs`.
+    fn add_signed(&self, rhs: &Self, sign: isize) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() + rhs.terms.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.terms.len() || j < rhs.terms.len() {
+            let left = self.terms.get(i).copied();
+            let right = rhs.terms.get(j).map(|&(n, c)| (n, sign * c));
+            match (left, right) {
+                (Some((n, c)), Some((m, d))) if n == m => {
+                    if c + d != 0 {
+                        terms.push((n, c + d));
+                    }
+                    i += 1;
+                    j += 1;
+                }
+                (Some((n, c)), Some((m, _))) if n < m => {
+                    terms.push((n, c));
+                    i += 1;
+                }
+                (Some(term), None) => {
+                    terms.push(term);
+                    i += 1;
+                }
+                (_, Some(term)) => {
+                    terms.push(term);
+                    j += 1;
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+        Self { terms }
+    }
+}
+
+/// Raises `x` to a power that might not fit in the `u32` that `isize::pow()` takes; past
+/// that, only 0 and ±1 don't overflow anyway.
+fn int_pow(x: isize, n: usize) -> isize {
+    match x {
+        0 | 1 => x.pow(n.min(1) as u32),
+        -1 => {
+            if n % 2 == 0 {
+                1
+            } else {
+                -1
+            }
+        }
+        _ => x.pow(u32::try_from(n).unwrap_or(u32::MAX)),
+    }
+}
+
+impl From<&Polynomial> for SparsePolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        let terms = poly.coeffs.iter().copied().enumerate();
+        Self {
+            terms: terms.filter(|&(_, c)| c != 0).collect(),
+        }
+    }
+}
+
+impl From<Polynomial> for SparsePolynomial {
+    fn from(poly: Polynomial) -> Self {
+        Self::from(&poly)
+    }
+}
+
+impl From<&SparsePolynomial> for Polynomial {
+    fn from(sparse: &SparsePolynomial) -> Self {
+        let mut coeffs = vec![0; (sparse.degree() + 1) as usize];
+        for &(n, c) in sparse.terms.iter() {
+            coeffs[n] = c;
+        }
+        Self { coeffs }
+    }
+}
+
+impl From<SparsePolynomial> for Polynomial {
+    fn from(sparse: SparsePolynomial) -> Self {
+        Self::from(&sparse)
+    }
+}
+
+impl std::fmt::Display for SparsePolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        PolyFormatter::new().write_terms(f, self.terms.iter().copied())
+    }
+}
+
+impl Add for &SparsePolynomial {
+    type Output = SparsePolynomial;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_signed(rhs, 1)
+    }
+}
+
+impl Add for SparsePolynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Sub for &SparsePolynomial {
+    type Output = SparsePolynomial;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_signed(rhs, -1)
+    }
+}
+
+impl Sub for SparsePolynomial {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Neg for SparsePolynomial {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        for term in self.terms.iter_mut() {
+            term.1 = -term.1;
+        }
+        self
+    }
+}
+
+impl Mul for &SparsePolynomial {
+    type Output = SparsePolynomial;
+
+    /// Multiplies two sparse polynomials by merging the rows `a_i * rhs` of the product
+    /// through a heap, which produces the terms in order of power while only keeping one
+    /// candidate per term of the shorter factor around.
+    ///
+    /// # Examples
+    /// ```
+    /// use polynomint::sparse::SparsePolynomial;
+    ///
+    /// let a = SparsePolynomial::new(vec![(0, -1), (500, 1)]); // x^500 - 1
+    /// let b = SparsePolynomial::new(vec![(0, 1), (500, 1)]); // x^500 + 1
+    /// assert_eq!(&a * &b, SparsePolynomial::new(vec![(0, -1), (1000, 1)]));
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (short, long) = if self.terms.len() <= rhs.terms.len() {
+            (&self.terms, &rhs.terms)
+        } else {
+            (&rhs.terms, &self.terms)
+        };
+        if short.is_empty() {
+            return SparsePolynomial::zero();
+        }
+        // each entry is (power of the product term, index into short, index into long);
+        // row i of the product only moves on to long[j + 1] once long[j] is popped
+        let mut heap: BinaryHeap<Reverse<(usize, usize, usize)>> = short
+            .iter()
+            .enumerate()
+            .map(|(i, &(n, _))| Reverse((n + long[0].0, i, 0)))
+            .collect();
+        let mut terms: Vec<(usize, isize)> = Vec::new();
+        while let Some(Reverse((n, i, j))) = heap.pop() {
+            let c = short[i].1 * long[j].1;
+            match terms.last_mut() {
+                Some(last) if last.0 == n => last.1 += c,
+                _ => {
+                    // the previous power is finished, so drop it if it cancelled out
+                    if terms.last().is_some_and(|&(_, c)| c == 0) {
+                        terms.pop();
+                    }
+                    terms.push((n, c));
+                }
+            }
+            if j + 1 < long.len() {
+                heap.push(Reverse((short[i].0 + long[j + 1].0, i, j + 1)));
+            }
+        }
+        if terms.last().is_some_and(|&(_, c)| c == 0) {
+            terms.pop();
+        }
+        SparsePolynomial { terms }
+    }
+}
+
+impl Mul for SparsePolynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
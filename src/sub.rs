@@ -6,7 +6,7 @@ impl Sub for Polynomial {
     type Output = Self;
     fn sub(mut self, rhs: Self) -> Self::Output {
         if self.is_zero() {
-            rhs
+            -rhs
         } else if rhs.is_zero() {
             self
         } else {
@@ -64,7 +64,7 @@ impl Sub<isize> for Polynomial {
     }
 }
 
-impl<'a> Sub<isize> for &'a Polynomial {
+impl Sub<isize> for &Polynomial {
     type Output = Polynomial;
     fn sub(self, rhs: isize) -> Self::Output {
         if self.is_zero() {
@@ -130,7 +130,7 @@ impl SubAssign for Polynomial {
     }
 }
 
-impl<'a> SubAssign<&'a Polynomial> for Polynomial {
+impl SubAssign<&Polynomial> for Polynomial {
     fn sub_assign(&mut self, rhs: &Self) {
         if self.is_zero() {
             *self = -rhs;